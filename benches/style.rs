@@ -0,0 +1,99 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use skeleton_rs::common::{
+    StyleParams, animation_class, build_class_names, build_skeleton_style, line_clamp_style,
+    text_line_blocks, wave_declaration, wave_duration,
+};
+use skeleton_rs::{Direction, HoverFilter, Theme};
+
+fn bench_style(c: &mut Criterion) {
+    c.bench_function("wave_duration/default", |b| {
+        b.iter(|| wave_duration(black_box(None)))
+    });
+    c.bench_function("wave_duration/sweeps_per_second", |b| {
+        b.iter(|| wave_duration(black_box(Some(2.5))))
+    });
+    c.bench_function("line_clamp_style", |b| {
+        b.iter(|| line_clamp_style(black_box(3)))
+    });
+    c.bench_function("theme_base_color", |b| {
+        b.iter(|| black_box(Theme::Custom("#0099ff")).base_color())
+    });
+    c.bench_function("build_skeleton_style", |b| {
+        let params = StyleParams::default();
+        b.iter(|| build_skeleton_style(black_box(&params)))
+    });
+    c.bench_function("build_class_names", |b| {
+        let params = StyleParams::default();
+        b.iter(|| build_class_names(black_box(&params), Some("skeleton-anim")))
+    });
+}
+
+/// Builds the style, animation, and classes of a skeleton the way the backends do on render.
+fn render_style(params: &StyleParams, direction: &Direction, timing: &str) -> (String, String) {
+    let declaration = wave_declaration(direction, timing);
+    let anim_class = animation_class(&declaration);
+    (
+        build_skeleton_style(params),
+        build_class_names(params, Some(&anim_class)),
+    )
+}
+
+fn bench_prop_sets(c: &mut Criterion) {
+    let dark = Theme::Dark.base_color();
+    c.bench_function("render_style/wave", |b| {
+        let params = StyleParams {
+            background_color: &dark,
+            width: "300px",
+            height: "1.5em",
+            ..StyleParams::default()
+        };
+        b.iter(|| render_style(black_box(&params), &Direction::LeftToRight, "1.6s linear"))
+    });
+    c.bench_function("render_style/custom_angle", |b| {
+        let params = StyleParams {
+            background_color: &dark,
+            ..StyleParams::default()
+        };
+        b.iter(|| {
+            let (style, classes) = render_style(
+                black_box(&params),
+                &Direction::CustomAngle(45),
+                "0.8s ease-in-out",
+            );
+            (style, classes, Direction::CustomAngle(45).wave_keyframes())
+        })
+    });
+    c.bench_function("render_style/multi_line_text", |b| {
+        let params = StyleParams {
+            width: "80%",
+            multi_line: true,
+            line_gap: "0.75em",
+            ..StyleParams::default()
+        };
+        b.iter(|| {
+            (
+                build_skeleton_style(black_box(&params)),
+                text_line_blocks(5, "1em", Some("1.5em"), "60%", "4px"),
+            )
+        })
+    });
+    c.bench_function("render_style/custom_style", |b| {
+        let hover_filter = HoverFilter::Brightness(0.9);
+        let params = StyleParams {
+            width: "calc(100% - 2rem)",
+            margin: "8px 16px",
+            logical_props: true,
+            max_width: Some("640px"),
+            max_lines: Some(3),
+            hover_filter: Some(&hover_filter),
+            animate_on_hover: true,
+            morph: true,
+            custom_style: "box-shadow: 0 1px 2px rgba(0, 0, 0, 0.1); outline-offset: 2px;",
+            ..StyleParams::default()
+        };
+        b.iter(|| render_style(black_box(&params), &Direction::RightToLeft, "1.6s linear"))
+    });
+}
+
+criterion_group!(benches, bench_style, bench_prop_sets);
+criterion_main!(benches);
@@ -1,7 +1,9 @@
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
-use skeleton_rs::dioxus::{Skeleton, SkeletonGroup};
-use skeleton_rs::{Animation, Theme, Variant};
+use skeleton_rs::dioxus::{
+    Skeleton, SkeletonGroup, SkeletonListItem, SkeletonMirror, SkeletonText,
+};
+use skeleton_rs::{Animation, Direction, GroupDirection, Theme, Variant};
 
 const FAVICON: Asset = asset!("/assets/favicon.ico");
 const MAIN_CSS: Asset = asset!("/assets/styles.css");
@@ -185,7 +187,8 @@ use skeleton_rs::dioxus::*;
 fn Example5() -> Element {{
     rsx! {{
         SkeletonGroup {{
-            style: "display: flex; gap: 1rem;",
+            direction: GroupDirection::Row,
+            gap: "1rem",
             Skeleton {{ variant: Variant::Rounded, width: "100px", height: "100px" }}
             Skeleton {{ variant: Variant::Rounded, width: "100px", height: "100px" }}
             Skeleton {{ variant: Variant::Rounded, width: "100px", height: "100px" }}
@@ -194,7 +197,8 @@ fn Example5() -> Element {{
 }}"#
             }
             SkeletonGroup {
-                style: "display: flex; gap: 1rem;",
+                direction: GroupDirection::Row,
+                gap: "1rem",
                 Skeleton { variant: Variant::Rounded, width: "100px", height: "100px" }
                 Skeleton { variant: Variant::Rounded, width: "100px", height: "100px" }
                 Skeleton { variant: Variant::Rounded, width: "100px", height: "100px" }
@@ -387,6 +391,764 @@ fn Example11() -> Element {{
     }
 }
 
+#[component]
+fn Example12() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Card Image Skeleton" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example12() -> Element {{
+    rsx! {{
+        div {{
+            class: "w-64 bg-white rounded-lg shadow",
+            Skeleton {{
+                variant: Variant::Image,
+                width: "100%",
+                height: "140px",
+                display: "block",
+                border_radius: "0",
+                border_radius_top: "8px",
+                animation: Animation::Wave
+            }}
+            div {{
+                class: "p-4 space-y-2",
+                Skeleton {{ variant: Variant::Text, width: "70%", height: "1.2em" }}
+                Skeleton {{ variant: Variant::Text, width: "90%", height: "1em" }}
+            }}
+        }}
+    }}
+}}"#
+            }
+            div {
+                class: "w-64 bg-white rounded-lg shadow",
+                Skeleton {
+                    variant: Variant::Image,
+                    width: "100%",
+                    height: "140px",
+                    display: "block",
+                    border_radius: "0",
+                    border_radius_top: "8px",
+                    animation: Animation::Wave
+                }
+                div {
+                    class: "p-4 space-y-2",
+                    Skeleton { variant: Variant::Text, width: "70%", height: "1.2em" }
+                    Skeleton { variant: Variant::Text, width: "90%", height: "1em" }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn Example13() -> Element {
+    let mut dark = use_signal(|| false);
+    let theme = if dark() { Theme::Dark } else { Theme::Light };
+
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Runtime Theme Switching" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example13() -> Element {{
+    let mut dark = use_signal(|| false);
+    let theme = if dark() {{ Theme::Dark }} else {{ Theme::Light }};
+
+    rsx! {{
+        div {{
+            class: "space-y-4",
+            button {{ onclick: move |_| dark.toggle(), "Toggle Theme" }}
+            Skeleton {{
+                variant: Variant::Rounded,
+                width: "200px",
+                height: "100px",
+                theme: theme
+            }}
+        }}
+    }}
+}}"#
+            }
+            div {
+                class: "space-y-4",
+                button { onclick: move |_| dark.toggle(), "Toggle Theme" }
+                Skeleton {
+                    variant: Variant::Rounded,
+                    width: "200px",
+                    height: "100px",
+                    theme: theme
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn Example14() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Toolbar Icons" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example14() -> Element {{
+    rsx! {{
+        SkeletonGroup {{
+            style: "display: flex; gap: 0.75rem;",
+            Skeleton {{ variant: Variant::Icon, icon_size: "24px" }}
+            Skeleton {{ variant: Variant::Icon, icon_size: "24px" }}
+            Skeleton {{ variant: Variant::Icon, icon_size: "24px" }}
+            Skeleton {{ variant: Variant::Icon, icon_size: "24px" }}
+        }}
+    }}
+}}"#
+            }
+            SkeletonGroup {
+                style: "display: flex; gap: 0.75rem;",
+                Skeleton { variant: Variant::Icon, icon_size: "24px" }
+                Skeleton { variant: Variant::Icon, icon_size: "24px" }
+                Skeleton { variant: Variant::Icon, icon_size: "24px" }
+                Skeleton { variant: Variant::Icon, icon_size: "24px" }
+            }
+        }
+    }
+}
+
+#[component]
+fn Example15() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Checkerboard Image" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example15() -> Element {{
+    rsx! {{
+        Skeleton {{
+            variant: Variant::Checkerboard,
+            width: "300px",
+            height: "200px",
+            animation: Animation::Wave
+        }}
+    }}
+}}"#
+            }
+            Skeleton {
+                variant: Variant::Checkerboard,
+                width: "300px",
+                height: "200px",
+                animation: Animation::Wave
+            }
+        }
+    }
+}
+
+#[component]
+fn Example16() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Outline Only" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example16() -> Element {{
+    rsx! {{
+        Skeleton {{
+            variant: Variant::Rounded,
+            width: "250px",
+            height: "120px",
+            outline_only: true,
+            animation: Animation::Wave
+        }}
+    }}
+}}"#
+            }
+            Skeleton {
+                variant: Variant::Rounded,
+                width: "250px",
+                height: "120px",
+                outline_only: true,
+                animation: Animation::Wave
+            }
+        }
+    }
+}
+
+#[component]
+fn Example17() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Divider" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example17() -> Element {{
+    rsx! {{
+        div {{
+            style: "width: 250px;",
+            Skeleton {{ width: "60%" }}
+            Skeleton {{
+                variant: Variant::Divider,
+                thickness: "2px",
+                animation: Animation::Wave,
+                margin: "12px 0"
+            }}
+            Skeleton {{ width: "80%" }}
+        }}
+    }}
+}}"#
+            }
+            div {
+                style: "width: 250px;",
+                Skeleton { width: "60%" }
+                Skeleton {
+                    variant: Variant::Divider,
+                    thickness: "2px",
+                    animation: Animation::Wave,
+                    margin: "12px 0"
+                }
+                Skeleton { width: "80%" }
+            }
+        }
+    }
+}
+
+#[component]
+fn Example18() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Mirror Layout" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example18() -> Element {{
+    rsx! {{
+        div {{
+            style: "display: flex; gap: 16px;",
+            div {{
+                id: "mirror-card",
+                style: "width: 200px;",
+                h3 {{ "Card title" }}
+                p {{ "Mirrored skeletons copy the size and position of every block." }}
+                button {{ "Action" }}
+            }}
+            SkeletonMirror {{ target_id: "mirror-card" }}
+        }}
+    }}
+}}"#
+            }
+            div {
+                style: "display: flex; gap: 16px;",
+                div {
+                    id: "mirror-card",
+                    style: "width: 200px;",
+                    h3 { "Card title" }
+                    p { "Mirrored skeletons copy the size and position of every block." }
+                    button { "Action" }
+                }
+                SkeletonMirror { target_id: "mirror-card" }
+            }
+        }
+    }
+}
+
+#[component]
+fn Example19() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Testimonial" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example19() -> Element {{
+    rsx! {{
+        div {{
+            style: "width: 300px;",
+            Skeleton {{ variant: Variant::Quote, quote_lines: 4 }}
+        }}
+    }}
+}}"#
+            }
+            div {
+                style: "width: 300px;",
+                Skeleton { variant: Variant::Quote, quote_lines: 4 }
+            }
+        }
+    }
+}
+
+#[component]
+fn Example20() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Card Grid" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example20() -> Element {{
+    rsx! {{
+        div {{
+            style: "display: grid; grid-template-columns: repeat(3, 1fr); gap: 12px; width: 100%;",
+            Skeleton {{ variant: Variant::Rounded, height: "120px", grid_span: 2 }}
+            Skeleton {{ variant: Variant::Rounded, height: "120px" }}
+            Skeleton {{ variant: Variant::Rounded, height: "120px" }}
+            Skeleton {{ variant: Variant::Rounded, height: "120px", grid_span: 2 }}
+        }}
+    }}
+}}"#
+            }
+            div {
+                style: "display: grid; grid-template-columns: repeat(3, 1fr); gap: 12px; width: 100%;",
+                Skeleton { variant: Variant::Rounded, height: "120px", grid_span: 2 }
+                Skeleton { variant: Variant::Rounded, height: "120px" }
+                Skeleton { variant: Variant::Rounded, height: "120px" }
+                Skeleton { variant: Variant::Rounded, height: "120px", grid_span: 2 }
+            }
+        }
+    }
+}
+
+#[component]
+fn Example21() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Visible Triggered" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example21() -> Element {{
+    rsx! {{
+        div {{
+            style: "display: flex; flex-direction: column; gap: 120vh;",
+            Skeleton {{ width: "300px", height: "1.5em", animate_on_visible: true }}
+            Skeleton {{ width: "300px", height: "1.5em", animate_on_visible: true }}
+            Skeleton {{ width: "300px", height: "1.5em", animate_on_visible: true }}
+        }}
+    }}
+}}"#
+            }
+            div {
+                style: "display: flex; flex-direction: column; gap: 120vh;",
+                Skeleton { width: "300px", height: "1.5em", animate_on_visible: true }
+                Skeleton { width: "300px", height: "1.5em", animate_on_visible: true }
+                Skeleton { width: "300px", height: "1.5em", animate_on_visible: true }
+            }
+        }
+    }
+}
+
+#[component]
+fn Example22() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Paragraph" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example22() -> Element {{
+    rsx! {{
+        div {{
+            style: "width: 320px;",
+            Skeleton {{ variant: Variant::Text, lines: 4 }}
+        }}
+    }}
+}}"#
+            }
+            div {
+                style: "width: 320px;",
+                Skeleton { variant: Variant::Text, lines: 4 }
+            }
+        }
+    }
+}
+
+#[component]
+fn Example23() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Inset Depth" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example23() -> Element {{
+    rsx! {{
+        div {{
+            style: "display: flex; gap: 16px;",
+            Skeleton {{ variant: Variant::Rounded, width: "160px", height: "100px" }}
+            Skeleton {{ variant: Variant::Rounded, width: "160px", height: "100px", inset_shadow: true }}
+        }}
+    }}
+}}"#
+            }
+            div {
+                style: "display: flex; gap: 16px;",
+                Skeleton { variant: Variant::Rounded, width: "160px", height: "100px" }
+                Skeleton { variant: Variant::Rounded, width: "160px", height: "100px", inset_shadow: true }
+            }
+        }
+    }
+}
+
+#[component]
+fn Example24() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Layout Template" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example24() -> Element {{
+    rsx! {{
+        Skeleton {{ width: "320px", template: "avatar | line(80%) line(60%) | button" }}
+    }}
+}}"#
+            }
+            Skeleton { width: "320px", template: "avatar | line(80%) line(60%) | button" }
+        }
+    }
+}
+
+#[component]
+fn Example25() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Staggered Timing" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example25() -> Element {{
+    rsx! {{
+        div {{
+            style: "display: flex; flex-direction: column; gap: 8px;",
+            Skeleton {{ width: "240px", animation_duration: "800ms" }}
+            Skeleton {{ width: "240px", animation_duration: "800ms", animation_delay: "150ms" }}
+            Skeleton {{ width: "240px", animation_duration: "800ms", animation_delay: "300ms" }}
+        }}
+    }}
+}}"#
+            }
+            div {
+                style: "display: flex; flex-direction: column; gap: 8px;",
+                Skeleton { width: "240px", animation_duration: "800ms" }
+                Skeleton { width: "240px", animation_duration: "800ms", animation_delay: "150ms" }
+                Skeleton { width: "240px", animation_duration: "800ms", animation_delay: "300ms" }
+            }
+        }
+    }
+}
+
+#[component]
+fn Example26() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Card" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example26() -> Element {{
+    rsx! {{
+        Skeleton {{ variant: Variant::Card, width: "280px", border_radius: "8px" }}
+    }}
+}}"#
+            }
+            Skeleton { variant: Variant::Card, width: "280px", border_radius: "8px" }
+        }
+    }
+}
+
+#[component]
+fn Example27() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Contact List" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example27() -> Element {{
+    rsx! {{
+        div {{
+            style: "display: flex; flex-direction: column; gap: 12px; width: 320px;",
+            SkeletonListItem {{}}
+            SkeletonListItem {{}}
+            SkeletonListItem {{}}
+            SkeletonListItem {{ lines: 3 }}
+        }}
+    }}
+}}"#
+            }
+            div {
+                style: "display: flex; flex-direction: column; gap: 12px; width: 320px;",
+                SkeletonListItem {}
+                SkeletonListItem {}
+                SkeletonListItem {}
+                SkeletonListItem { lines: 3 }
+            }
+        }
+    }
+}
+
+#[component]
+fn Example28() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Table" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example28() -> Element {{
+    rsx! {{
+        Skeleton {{
+            variant: Variant::Table,
+            rows: 5,
+            columns: 4,
+            width: "320px",
+            animation: Animation::Wave,
+        }}
+    }}
+}}"#
+            }
+            Skeleton {
+                variant: Variant::Table,
+                rows: 5,
+                columns: 4,
+                width: "320px",
+                animation: Animation::Wave,
+            }
+        }
+    }
+}
+
+#[component]
+fn Example29() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Shimmer" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example29() -> Element {{
+    rsx! {{
+        Skeleton {{
+            variant: Variant::Rectangular,
+            width: "300px",
+            height: "120px",
+            animation: Animation::Shimmer,
+            direction: Direction::CustomAngle(120),
+        }}
+    }}
+}}"#
+            }
+            Skeleton {
+                variant: Variant::Rectangular,
+                width: "300px",
+                height: "120px",
+                animation: Animation::Shimmer,
+                direction: Direction::CustomAngle(120),
+            }
+        }
+    }
+}
+
+#[component]
+fn Example30() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Paragraph" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example30() -> Element {{
+    rsx! {{
+        SkeletonText {{ lines: 4, seed: 42, height: "0.9em" }}
+    }}
+}}"#
+            }
+            SkeletonText { lines: 4, seed: 42, height: "0.9em" }
+        }
+    }
+}
+
+#[component]
+fn Example31() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Paragraph Metrics" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example31() -> Element {{
+    rsx! {{
+        Skeleton {{
+            variant: Variant::Text,
+            lines: 3,
+            width: "300px",
+            height: "0.75em",
+            line_height_each: "1.5em",
+            line_gap: "0",
+        }}
+    }}
+}}"#
+            }
+            Skeleton {
+                variant: Variant::Text,
+                lines: 3,
+                width: "300px",
+                height: "0.75em",
+                line_height_each: "1.5em",
+                line_gap: "0",
+            }
+        }
+    }
+}
+
+#[component]
+fn Example32() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Glow" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example32() -> Element {{
+    rsx! {{
+        Skeleton {{
+            variant: Variant::Circular,
+            animation: Animation::Glow,
+            width: "64px",
+            height: "64px",
+        }}
+    }}
+}}"#
+            }
+            Skeleton {
+                variant: Variant::Circular,
+                animation: Animation::Glow,
+                width: "64px",
+                height: "64px",
+            }
+        }
+    }
+}
+
+#[component]
+fn Example33() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
+            h2 { class: "text-xl font-bold mb-2", "Blog Post Summary" }
+            pre {
+                class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
+                r#"use dioxus::prelude::*;
+use skeleton_rs::dioxus::*;
+
+#[component]
+fn Example33() -> Element {{
+    rsx! {{
+        Skeleton {{ variant: Variant::Paragraph, lines: 3, width: "320px", height: "0.8em",
+            article {{
+                h3 {{ "Shipping skeletons in Rust" }}
+                p {{ "How we replaced spinners with layout-matching placeholders across our Yew, Dioxus, and Leptos apps." }}
+            }}
+        }}
+    }}
+}}"#
+            }
+            Skeleton { variant: Variant::Paragraph, lines: 3, width: "320px", height: "0.8em",
+                article {
+                    h3 { "Shipping skeletons in Rust" }
+                    p { "How we replaced spinners with layout-matching placeholders across our Yew, Dioxus, and Leptos apps." }
+                }
+            }
+        }
+    }
+}
+
 #[component]
 fn Examples() -> Element {
     rsx! {
@@ -405,6 +1167,28 @@ fn Examples() -> Element {
                 Example8 {}
                 Example10 {}
                 Example11 {}
+                Example12 {}
+                Example13 {}
+                Example14 {}
+                Example15 {}
+                Example16 {}
+                Example17 {}
+                Example18 {}
+                Example19 {}
+                Example20 {}
+                Example21 {}
+                Example22 {}
+                Example23 {}
+                Example24 {}
+                Example25 {}
+                Example26 {}
+                Example27 {}
+                Example28 {}
+                Example29 {}
+                Example30 {}
+                Example31 {}
+                Example32 {}
+                Example33 {}
             }
         }
     }
@@ -0,0 +1,216 @@
+use leptos::prelude::*;
+use skeleton_rs::leptos::{Skeleton, SkeletonGroup};
+use skeleton_rs::{Animation, GroupDirection, Theme, Variant};
+
+fn main() {
+    console_error_panic_hook::set_once();
+    mount_to_body(Examples);
+}
+
+#[component]
+fn Example1() -> impl IntoView {
+    view! {
+        <div class="flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md">
+            <h2 class="text-xl font-bold mb-2">"Basic Skeleton"</h2>
+            <pre class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto">
+                {r#"use leptos::prelude::*;
+use skeleton_rs::leptos::Skeleton;
+use skeleton_rs::Variant;
+
+#[component]
+fn Example1() -> impl IntoView {
+    view! {
+        <Skeleton variant=Variant::Image width="300px" height="200px" />
+    }
+}"#}
+            </pre>
+            <Skeleton variant=Variant::Image width="300px" height="200px" />
+        </div>
+    }
+}
+
+#[component]
+fn Example2() -> impl IntoView {
+    view! {
+        <div class="flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md">
+            <h2 class="text-xl font-bold mb-2">"Wave Text"</h2>
+            <pre class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto">
+                {r#"use leptos::prelude::*;
+use skeleton_rs::leptos::Skeleton;
+use skeleton_rs::{Animation, Variant};
+
+#[component]
+fn Example2() -> impl IntoView {
+    view! {
+        <Skeleton
+            variant=Variant::Text
+            width="100%"
+            height="1.5em"
+            border_radius="0"
+            animation=Animation::Wave
+        />
+    }
+}"#}
+            </pre>
+            <Skeleton
+                variant=Variant::Text
+                width="100%"
+                height="1.5em"
+                border_radius="0"
+                animation=Animation::Wave
+            />
+        </div>
+    }
+}
+
+#[component]
+fn Example3() -> impl IntoView {
+    view! {
+        <div class="flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md">
+            <h2 class="text-xl font-bold mb-2">"Dark Avatar"</h2>
+            <pre class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto">
+                {r#"use leptos::prelude::*;
+use skeleton_rs::leptos::Skeleton;
+use skeleton_rs::{Animation, Theme, Variant};
+
+#[component]
+fn Example3() -> impl IntoView {
+    view! {
+        <Skeleton
+            variant=Variant::Avatar
+            width="80px"
+            height="80px"
+            theme=Theme::Dark
+            animation=Animation::Pulse
+        />
+    }
+}"#}
+            </pre>
+            <Skeleton
+                variant=Variant::Avatar
+                width="80px"
+                height="80px"
+                theme=Theme::Dark
+                animation=Animation::Pulse
+            />
+        </div>
+    }
+}
+
+#[component]
+fn Example4() -> impl IntoView {
+    view! {
+        <div class="flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md">
+            <h2 class="text-xl font-bold mb-2">"Skeleton Group"</h2>
+            <pre class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto">
+                {r#"use leptos::prelude::*;
+use skeleton_rs::leptos::{Skeleton, SkeletonGroup};
+use skeleton_rs::{GroupDirection, Variant};
+
+#[component]
+fn Example4() -> impl IntoView {
+    view! {
+        <SkeletonGroup direction=GroupDirection::Row gap="1rem">
+            <Skeleton variant=Variant::Rounded width="100px" height="100px" />
+            <Skeleton variant=Variant::Rounded width="100px" height="100px" />
+            <Skeleton variant=Variant::Rounded width="100px" height="100px" />
+        </SkeletonGroup>
+    }
+}"#}
+            </pre>
+            <SkeletonGroup direction=GroupDirection::Row gap="1rem">
+                <Skeleton variant=Variant::Rounded width="100px" height="100px" />
+                <Skeleton variant=Variant::Rounded width="100px" height="100px" />
+                <Skeleton variant=Variant::Rounded width="100px" height="100px" />
+            </SkeletonGroup>
+        </div>
+    }
+}
+
+#[component]
+fn Example5() -> impl IntoView {
+    view! {
+        <div class="flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md">
+            <h2 class="text-xl font-bold mb-2">"Animate on Visible"</h2>
+            <pre class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto">
+                {r#"use leptos::prelude::*;
+use skeleton_rs::leptos::Skeleton;
+use skeleton_rs::{Animation, Variant};
+
+#[component]
+fn Example5() -> impl IntoView {
+    view! {
+        <Skeleton
+            variant=Variant::Text
+            animation=Animation::Wave
+            animate_on_visible=true
+            width="150px"
+            height="40px"
+        />
+    }
+}"#}
+            </pre>
+            <Skeleton
+                variant=Variant::Text
+                animation=Animation::Wave
+                animate_on_visible=true
+                width="150px"
+                height="40px"
+            />
+        </div>
+    }
+}
+
+#[component]
+fn Example6() -> impl IntoView {
+    let (loaded, set_loaded) = signal(false);
+    view! {
+        <div class="flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md">
+            <h2 class="text-xl font-bold mb-2">"Toggle Content"</h2>
+            <pre class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto">
+                {r#"use leptos::prelude::*;
+use skeleton_rs::leptos::Skeleton;
+use skeleton_rs::Variant;
+
+#[component]
+fn Example6() -> impl IntoView {
+    let (loaded, set_loaded) = signal(false);
+    view! {
+        <button on:click=move |_| set_loaded.update(|loaded| *loaded = !*loaded)>
+            "Toggle"
+        </button>
+        <Skeleton variant=Variant::Text lines=3 width="200px" show=loaded>
+            <p>"Loaded content"</p>
+        </Skeleton>
+    }
+}"#}
+            </pre>
+            <button
+                class="mb-4 px-4 py-2 bg-gray-800 text-white rounded-md"
+                on:click=move |_| set_loaded.update(|loaded| *loaded = !*loaded)
+            >
+                "Toggle"
+            </button>
+            <Skeleton variant=Variant::Text lines=3 width="200px" show=loaded>
+                <p>"Loaded content"</p>
+            </Skeleton>
+        </div>
+    }
+}
+
+#[component]
+fn Examples() -> impl IntoView {
+    view! {
+        <div class="m-6 min-h-screen flex flex-col items-center justify-center">
+            <h1 class="text-3xl font-bold mb-8 text-white">"Skeleton RS Leptos Examples"</h1>
+            <div class="grid grid-cols-1 sm:grid-cols-2 md:grid-cols-3 gap-8">
+                <Example1 />
+                <Example2 />
+                <Example3 />
+                <Example4 />
+                <Example5 />
+                <Example6 />
+            </div>
+        </div>
+    }
+}
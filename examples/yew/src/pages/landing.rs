@@ -1,7 +1,9 @@
 use gloo_net::http::Request;
 use serde::Deserialize;
-use skeleton_rs::yew::{Skeleton, SkeletonGroup};
-use skeleton_rs::{Animation, Theme, Variant, Direction};
+use skeleton_rs::yew::{
+    Skeleton, SkeletonGroup, SkeletonListItem, SkeletonMirror, SkeletonText,
+};
+use skeleton_rs::{Animation, Theme, Variant, Direction, GroupDirection};
 use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
 
@@ -56,7 +58,7 @@ pub fn example4() -> Html {
 #[function_component(Example5)]
 pub fn example5() -> Html {
     html! {
-        <SkeletonGroup style="display: flex; gap: 1rem;">
+        <SkeletonGroup direction={GroupDirection::Row} gap="1rem">
             <Skeleton variant={Variant::Rounded} width="100px" height="100px" />
             <Skeleton variant={Variant::Rounded} width="100px" height="100px" />
             <Skeleton variant={Variant::Rounded} width="100px" height="100px" />
@@ -177,13 +179,274 @@ pub fn example11() -> Html {
     }
 }
 
+#[function_component(Example12)]
+pub fn example12() -> Html {
+    html! {
+        <div class="w-64 bg-white rounded-lg shadow">
+            <Skeleton
+                variant={Variant::Image}
+                width="100%"
+                height="140px"
+                display="block"
+                border_radius="0"
+                border_radius_top="8px"
+                animation={Animation::Wave}
+            />
+            <div class="p-4 space-y-2">
+                <Skeleton variant={Variant::Text} width="70%" height="1.2em" />
+                <Skeleton variant={Variant::Text} width="90%" height="1em" />
+            </div>
+        </div>
+    }
+}
+
+#[function_component(Example13)]
+pub fn example13() -> Html {
+    let dark = use_state(|| false);
+    let toggle = {
+        let dark = dark.clone();
+        Callback::from(move |_| dark.set(!*dark))
+    };
+    let theme = if *dark { Theme::Dark } else { Theme::Light };
+
+    html! {
+        <div class="space-y-4">
+            <button onclick={toggle}>{ "Toggle Theme" }</button>
+            <Skeleton variant={Variant::Rounded} width="200px" height="100px" theme={theme} />
+        </div>
+    }
+}
+
+#[function_component(Example14)]
+pub fn example14() -> Html {
+    html! {
+        <SkeletonGroup style="display: flex; gap: 0.75rem;">
+            <Skeleton variant={Variant::Icon} icon_size="24px" />
+            <Skeleton variant={Variant::Icon} icon_size="24px" />
+            <Skeleton variant={Variant::Icon} icon_size="24px" />
+            <Skeleton variant={Variant::Icon} icon_size="24px" />
+        </SkeletonGroup>
+    }
+}
+
+#[function_component(Example15)]
+pub fn example15() -> Html {
+    html! {
+        <Skeleton
+            variant={Variant::Checkerboard}
+            width="300px"
+            height="200px"
+            animation={Animation::Wave}
+        />
+    }
+}
+
+#[function_component(Example16)]
+pub fn example16() -> Html {
+    html! {
+        <Skeleton
+            variant={Variant::Rounded}
+            width="250px"
+            height="120px"
+            outline_only=true
+            animation={Animation::Wave}
+        />
+    }
+}
+
+#[function_component(Example17)]
+pub fn example17() -> Html {
+    html! {
+        <div style="width: 250px;">
+            <Skeleton width="60%" />
+            <Skeleton variant={Variant::Divider} thickness="2px" animation={Animation::Wave} margin="12px 0" />
+            <Skeleton width="80%" />
+        </div>
+    }
+}
+
+#[function_component(Example18)]
+pub fn example18() -> Html {
+    let card = use_node_ref();
+
+    html! {
+        <div style="display: flex; gap: 16px;">
+            <div ref={card.clone()} style="width: 200px;">
+                <h3>{ "Card title" }</h3>
+                <p>{ "Mirrored skeletons copy the size and position of every block." }</p>
+                <button>{ "Action" }</button>
+            </div>
+            <SkeletonMirror target={card} />
+        </div>
+    }
+}
+
+#[function_component(Example19)]
+pub fn example19() -> Html {
+    html! {
+        <div style="width: 300px;">
+            <Skeleton variant={Variant::Quote} quote_lines={4} />
+        </div>
+    }
+}
+
+#[function_component(Example20)]
+pub fn example20() -> Html {
+    html! {
+        <div style="display: grid; grid-template-columns: repeat(3, 1fr); gap: 12px; width: 100%;">
+            <Skeleton variant={Variant::Rounded} height="120px" grid_span={2} />
+            <Skeleton variant={Variant::Rounded} height="120px" />
+            <Skeleton variant={Variant::Rounded} height="120px" />
+            <Skeleton variant={Variant::Rounded} height="120px" grid_span={2} />
+        </div>
+    }
+}
+
+#[function_component(Example21)]
+pub fn example21() -> Html {
+    html! {
+        <div style="display: flex; flex-direction: column; gap: 120vh;">
+            <Skeleton width="300px" height="1.5em" animate_on_visible={true} />
+            <Skeleton width="300px" height="1.5em" animate_on_visible={true} />
+            <Skeleton width="300px" height="1.5em" animate_on_visible={true} />
+        </div>
+    }
+}
+
+#[function_component(Example22)]
+pub fn example22() -> Html {
+    html! {
+        <div style="width: 320px;">
+            <Skeleton variant={Variant::Text} lines={4} />
+        </div>
+    }
+}
+
+#[function_component(Example23)]
+pub fn example23() -> Html {
+    html! {
+        <div style="display: flex; gap: 16px;">
+            <Skeleton variant={Variant::Rounded} width="160px" height="100px" />
+            <Skeleton variant={Variant::Rounded} width="160px" height="100px" inset_shadow={true} />
+        </div>
+    }
+}
+
+#[function_component(Example24)]
+pub fn example24() -> Html {
+    html! {
+        <Skeleton width="320px" template="avatar | line(80%) line(60%) | button" />
+    }
+}
+
+#[function_component(Example25)]
+pub fn example25() -> Html {
+    html! {
+        <div style="display: flex; flex-direction: column; gap: 8px;">
+            <Skeleton width="240px" animation_duration="800ms" />
+            <Skeleton width="240px" animation_duration="800ms" animation_delay="150ms" />
+            <Skeleton width="240px" animation_duration="800ms" animation_delay="300ms" />
+        </div>
+    }
+}
+
+#[function_component(Example26)]
+pub fn example26() -> Html {
+    html! {
+        <Skeleton variant={Variant::Card} width="280px" border_radius="8px" />
+    }
+}
+
+#[function_component(Example27)]
+pub fn example27() -> Html {
+    html! {
+        <div style="display: flex; flex-direction: column; gap: 12px; width: 320px;">
+            <SkeletonListItem />
+            <SkeletonListItem />
+            <SkeletonListItem />
+            <SkeletonListItem lines={3} />
+        </div>
+    }
+}
+
+#[function_component(Example28)]
+pub fn example28() -> Html {
+    html! {
+        <Skeleton
+            variant={Variant::Table}
+            rows={5}
+            columns={4}
+            width="320px"
+            animation={Animation::Wave}
+        />
+    }
+}
+
+#[function_component(Example29)]
+pub fn example29() -> Html {
+    html! {
+        <Skeleton
+            variant={Variant::Rectangular}
+            width="300px"
+            height="120px"
+            animation={Animation::Shimmer}
+            direction={Direction::CustomAngle(120)}
+        />
+    }
+}
+
+#[function_component(Example30)]
+pub fn example30() -> Html {
+    html! {
+        <SkeletonText lines={4} seed={42} height="0.9em" />
+    }
+}
+
+#[function_component(Example31)]
+pub fn example31() -> Html {
+    html! {
+        <Skeleton
+            variant={Variant::Text}
+            lines={3}
+            width="300px"
+            height="0.75em"
+            line_height_each="1.5em"
+            line_gap="0"
+        />
+    }
+}
+
+#[function_component(Example32)]
+pub fn example32() -> Html {
+    html! {
+        <Skeleton
+            variant={Variant::Circular}
+            animation={Animation::Glow}
+            width="64px"
+            height="64px"
+        />
+    }
+}
+
+#[function_component(Example33)]
+pub fn example33() -> Html {
+    html! {
+        <Skeleton variant={Variant::Paragraph} lines={3} width="320px" height="0.8em">
+            <article>
+                <h3>{ "Shipping skeletons in Rust" }</h3>
+                <p>{ "How we replaced spinners with layout-matching placeholders across our Yew, Dioxus, and Leptos apps." }</p>
+            </article>
+        </Skeleton>
+    }
+}
+
 #[function_component(LandingPage)]
 pub fn landing_page() -> Html {
     html! {
         <div class="m-6 min-h-screen flex flex-col items-center justify-center">
             <h1 class="text-3xl font-bold mb-8 text-white">{ "Skeleton RS Yew Examples" }</h1>
             <div class="grid grid-cols-1 sm:grid-cols-2 md:grid-cols-3 gap-8">
-                { (1..=11).map(|i| {
+                { (1..=33).map(|i| {
                         let (title, component, code) = match i {
                             1 => ("Basic Skeleton", html! { <Example1 /> }, r#"use yew::prelude::*;
 use skeleton_rs::yew::Skeleton;
@@ -247,13 +510,13 @@ pub fn example4() -> Html {
     }
 }"#),
                             5 => ("Skeleton Group", html! { <Example5 /> }, r#"use yew::prelude::*;
-use skeleton_rs::yew::{Skeleton, SkeletonGroup};
-use skeleton_rs::Variant;
+use skeleton_rs::yew::{Skeleton, SkeletonGroup, SkeletonMirror};
+use skeleton_rs::{GroupDirection, Variant};
 
 #[function_component(Example5)]
 pub fn example5() -> Html {
     html! {
-        <SkeletonGroup style="display: flex; gap: 1rem;">
+        <SkeletonGroup direction={GroupDirection::Row} gap="1rem">
             <Skeleton variant={Variant::Rounded} width="100px" height="100px" />
             <Skeleton variant={Variant::Rounded} width="100px" height="100px" />
             <Skeleton variant={Variant::Rounded} width="100px" height="100px" />
@@ -312,7 +575,7 @@ pub fn example8() -> Html {
                             9 => ("Fetch Data Example", html! { <Example9 /> }, r#"use yew::prelude::*;
 use gloo_net::http::Request;
 use serde::Deserialize;
-use skeleton_rs::yew::{Skeleton, SkeletonGroup};
+use skeleton_rs::yew::{Skeleton, SkeletonGroup, SkeletonMirror};
 use skeleton_rs::Variant;
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
@@ -409,6 +672,327 @@ pub fn example11() -> Html {
             custom_style="border: 2px dashed red;"
         />
     }
+}"#),
+                            12 => ("Card Image Skeleton", html! { <Example12 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::{Animation, Variant};
+
+#[function_component(Example12)]
+pub fn example12() -> Html {
+    html! {
+        <div class="w-64 bg-white rounded-lg shadow">
+            <Skeleton
+                variant={Variant::Image}
+                width="100%"
+                height="140px"
+                display="block"
+                border_radius="0"
+                border_radius_top="8px"
+                animation={Animation::Wave}
+            />
+            <div class="p-4 space-y-2">
+                <Skeleton variant={Variant::Text} width="70%" height="1.2em" />
+                <Skeleton variant={Variant::Text} width="90%" height="1em" />
+            </div>
+        </div>
+    }
+}"#),
+                            13 => ("Runtime Theme Switching", html! { <Example13 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::{Theme, Variant};
+
+#[function_component(Example13)]
+pub fn example13() -> Html {
+    let dark = use_state(|| false);
+    let toggle = {
+        let dark = dark.clone();
+        Callback::from(move |_| dark.set(!*dark))
+    };
+    let theme = if *dark { Theme::Dark } else { Theme::Light };
+
+    html! {
+        <div class="space-y-4">
+            <button onclick={toggle}>{ "Toggle Theme" }</button>
+            <Skeleton variant={Variant::Rounded} width="200px" height="100px" theme={theme} />
+        </div>
+    }
+}"#),
+                            14 => ("Toolbar Icons", html! { <Example14 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::{Skeleton, SkeletonGroup, SkeletonMirror};
+use skeleton_rs::Variant;
+
+#[function_component(Example14)]
+pub fn example14() -> Html {
+    html! {
+        <SkeletonGroup style="display: flex; gap: 0.75rem;">
+            <Skeleton variant={Variant::Icon} icon_size="24px" />
+            <Skeleton variant={Variant::Icon} icon_size="24px" />
+            <Skeleton variant={Variant::Icon} icon_size="24px" />
+            <Skeleton variant={Variant::Icon} icon_size="24px" />
+        </SkeletonGroup>
+    }
+}"#),
+                            15 => ("Checkerboard Image", html! { <Example15 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::{Animation, Variant};
+
+#[function_component(Example15)]
+pub fn example15() -> Html {
+    html! {
+        <Skeleton
+            variant={Variant::Checkerboard}
+            width="300px"
+            height="200px"
+            animation={Animation::Wave}
+        />
+    }
+}"#),
+                            16 => ("Outline Only", html! { <Example16 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::{Animation, Variant};
+
+#[function_component(Example16)]
+pub fn example16() -> Html {
+    html! {
+        <Skeleton
+            variant={Variant::Rounded}
+            width="250px"
+            height="120px"
+            outline_only=true
+            animation={Animation::Wave}
+        />
+    }
+}"#),
+                            17 => ("Divider", html! { <Example17 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::{Animation, Variant};
+
+#[function_component(Example17)]
+pub fn example17() -> Html {
+    html! {
+        <div style="width: 250px;">
+            <Skeleton width="60%" />
+            <Skeleton variant={Variant::Divider} thickness="2px" animation={Animation::Wave} margin="12px 0" />
+            <Skeleton width="80%" />
+        </div>
+    }
+}"#),
+                            18 => ("Mirror Layout", html! { <Example18 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::SkeletonMirror;
+
+#[function_component(Example18)]
+pub fn example18() -> Html {
+    let card = use_node_ref();
+
+    html! {
+        <div style="display: flex; gap: 16px;">
+            <div ref={card.clone()} style="width: 200px;">
+                <h3>{ "Card title" }</h3>
+                <p>{ "Mirrored skeletons copy the size and position of every block." }</p>
+                <button>{ "Action" }</button>
+            </div>
+            <SkeletonMirror target={card} />
+        </div>
+    }
+}"#),
+                            19 => ("Testimonial", html! { <Example19 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::Variant;
+
+#[function_component(Example19)]
+pub fn example19() -> Html {
+    html! {
+        <div style="width: 300px;">
+            <Skeleton variant={Variant::Quote} quote_lines={4} />
+        </div>
+    }
+}"#),
+                            20 => ("Card Grid", html! { <Example20 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::Variant;
+
+#[function_component(Example20)]
+pub fn example20() -> Html {
+    html! {
+        <div style="display: grid; grid-template-columns: repeat(3, 1fr); gap: 12px; width: 100%;">
+            <Skeleton variant={Variant::Rounded} height="120px" grid_span={2} />
+            <Skeleton variant={Variant::Rounded} height="120px" />
+            <Skeleton variant={Variant::Rounded} height="120px" />
+            <Skeleton variant={Variant::Rounded} height="120px" grid_span={2} />
+        </div>
+    }
+}"#),
+                            21 => ("Visible Triggered", html! { <Example21 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+
+#[function_component(Example21)]
+pub fn example21() -> Html {
+    html! {
+        <div style="display: flex; flex-direction: column; gap: 120vh;">
+            <Skeleton width="300px" height="1.5em" animate_on_visible={true} />
+            <Skeleton width="300px" height="1.5em" animate_on_visible={true} />
+            <Skeleton width="300px" height="1.5em" animate_on_visible={true} />
+        </div>
+    }
+}"#),
+                            22 => ("Paragraph", html! { <Example22 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::Variant;
+
+#[function_component(Example22)]
+pub fn example22() -> Html {
+    html! {
+        <div style="width: 320px;">
+            <Skeleton variant={Variant::Text} lines={4} />
+        </div>
+    }
+}"#),
+                            23 => ("Inset Depth", html! { <Example23 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::Variant;
+
+#[function_component(Example23)]
+pub fn example23() -> Html {
+    html! {
+        <div style="display: flex; gap: 16px;">
+            <Skeleton variant={Variant::Rounded} width="160px" height="100px" />
+            <Skeleton variant={Variant::Rounded} width="160px" height="100px" inset_shadow={true} />
+        </div>
+    }
+}"#),
+                            24 => ("Layout Template", html! { <Example24 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+
+#[function_component(Example24)]
+pub fn example24() -> Html {
+    html! {
+        <Skeleton width="320px" template="avatar | line(80%) line(60%) | button" />
+    }
+}"#),
+                            25 => ("Staggered Timing", html! { <Example25 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+
+#[function_component(Example25)]
+pub fn example25() -> Html {
+    html! {
+        <div style="display: flex; flex-direction: column; gap: 8px;">
+            <Skeleton width="240px" animation_duration="800ms" />
+            <Skeleton width="240px" animation_duration="800ms" animation_delay="150ms" />
+            <Skeleton width="240px" animation_duration="800ms" animation_delay="300ms" />
+        </div>
+    }
+}"#),
+                            26 => ("Card", html! { <Example26 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::Variant;
+
+#[function_component(Example26)]
+pub fn example26() -> Html {
+    html! {
+        <Skeleton variant={Variant::Card} width="280px" border_radius="8px" />
+    }
+}"#),
+                            27 => ("Contact List", html! { <Example27 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::SkeletonListItem;
+
+#[function_component(Example27)]
+pub fn example27() -> Html {
+    html! {
+        <div style="display: flex; flex-direction: column; gap: 12px; width: 320px;">
+            <SkeletonListItem />
+            <SkeletonListItem />
+            <SkeletonListItem />
+            <SkeletonListItem lines={3} />
+        </div>
+    }
+}"#),
+                            28 => ("Table", html! { <Example28 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::{Animation, Variant};
+
+#[function_component(Example28)]
+pub fn example28() -> Html {
+    html! {
+        <Skeleton
+            variant={Variant::Table}
+            rows={5}
+            columns={4}
+            width="320px"
+            animation={Animation::Wave}
+        />
+    }
+}"#),
+                            29 => ("Shimmer", html! { <Example29 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::{Animation, Direction, Variant};
+
+#[function_component(Example29)]
+pub fn example29() -> Html {
+    html! {
+        <Skeleton
+            variant={Variant::Rectangular}
+            width="300px"
+            height="120px"
+            animation={Animation::Shimmer}
+            direction={Direction::CustomAngle(120)}
+        />
+    }
+}"#),
+                            30 => ("Paragraph", html! { <Example30 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::SkeletonText;
+
+#[function_component(Example30)]
+pub fn example30() -> Html {
+    html! {
+        <SkeletonText lines={4} seed={42} height="0.9em" />
+    }
+}"#),
+                            31 => ("Paragraph Metrics", html! { <Example31 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::Variant;
+
+#[function_component(Example31)]
+pub fn example31() -> Html {
+    html! {
+        <Skeleton
+            variant={Variant::Text}
+            lines={3}
+            width="300px"
+            height="0.75em"
+            line_height_each="1.5em"
+            line_gap="0"
+        />
+    }
+}"#),
+                            32 => ("Glow", html! { <Example32 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::{Animation, Variant};
+
+#[function_component(Example32)]
+pub fn example32() -> Html {
+    html! {
+        <Skeleton
+            variant={Variant::Circular}
+            animation={Animation::Glow}
+            width="64px"
+            height="64px"
+        />
+    }
+}"#),
+                            33 => ("Blog Post Summary", html! { <Example33 /> }, r#"use yew::prelude::*;
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::Variant;
+
+#[function_component(Example33)]
+pub fn example33() -> Html {
+    html! {
+        <Skeleton variant={Variant::Paragraph} lines={3} width="320px" height="0.8em">
+            <article>
+                <h3>{ "Shipping skeletons in Rust" }</h3>
+                <p>{ "How we replaced spinners with layout-matching placeholders across our Yew, Dioxus, and Leptos apps." }</p>
+            </article>
+        </Skeleton>
+    }
 }"#),
                             _ => unreachable!()
                         };
@@ -1,4 +1,11 @@
-#[derive(Clone, PartialEq, Default)]
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::OnceLock;
+
+#[derive(Clone, Copy, PartialEq, Default)]
 pub enum Variant {
     #[default]
     Text,
@@ -8,6 +15,43 @@ pub enum Variant {
     Image,
     Avatar,
     Button,
+    Icon,
+    Checkerboard,
+    Divider,
+    Quote,
+    Card,
+    Table,
+    /// A taller heading bar followed by `lines` body lines, like an article preview.
+    Paragraph,
+}
+
+impl Variant {
+    /// Returns the kebab-case name of the variant, rendered as the skeleton's `data-variant`.
+    ///
+    /// ```rust
+    /// use skeleton_rs::Variant;
+    ///
+    /// assert_eq!(Variant::Text.as_str(), "text");
+    /// assert_eq!(Variant::Checkerboard.as_str(), "checkerboard");
+    /// ```
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Variant::Text => "text",
+            Variant::Circular => "circular",
+            Variant::Rectangular => "rectangular",
+            Variant::Rounded => "rounded",
+            Variant::Image => "image",
+            Variant::Avatar => "avatar",
+            Variant::Button => "button",
+            Variant::Icon => "icon",
+            Variant::Checkerboard => "checkerboard",
+            Variant::Divider => "divider",
+            Variant::Quote => "quote",
+            Variant::Card => "card",
+            Variant::Table => "table",
+            Variant::Paragraph => "paragraph",
+        }
+    }
 }
 
 #[derive(Clone, PartialEq, Default)]
@@ -15,15 +59,566 @@ pub enum Animation {
     #[default]
     Pulse,
     Wave,
+    PulseGradient,
+    /// Sweeps a narrow, bright band across the skeleton at the `Direction` angle.
+    Shimmer,
+    /// Pulses a soft glow of the theme color around the skeleton through `box-shadow`.
+    Glow,
     None,
 }
 
+impl Animation {
+    /// Returns the kebab-case name of the animation, rendered as the skeleton's
+    /// `data-animation`.
+    ///
+    /// ```rust
+    /// use skeleton_rs::Animation;
+    ///
+    /// assert_eq!(Animation::Wave.as_str(), "wave");
+    /// assert_eq!(Animation::PulseGradient.as_str(), "pulse-gradient");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Animation::Pulse => "pulse",
+            Animation::Wave => "wave",
+            Animation::PulseGradient => "pulse-gradient",
+            Animation::Shimmer => "shimmer",
+            Animation::Glow => "glow",
+            Animation::None => "none",
+        }
+    }
+}
+
+/// What the pulse animation varies.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum PulseMode {
+    /// Fades the whole element's opacity, revealing what is behind it.
+    #[default]
+    Opacity,
+    /// Fades the background color between the base color and a lighter tint, keeping the
+    /// element fully opaque.
+    Color,
+}
+
+impl PulseMode {
+    /// Returns the keyframes used by this mode.
+    pub fn keyframes(self) -> &'static str {
+        match self {
+            PulseMode::Opacity => PULSE_KEYFRAMES,
+            PulseMode::Color => COLOR_PULSE_KEYFRAMES,
+        }
+    }
+
+    /// Returns the name of the keyframes used by this mode.
+    pub fn keyframes_name(self) -> &'static str {
+        match self {
+            PulseMode::Opacity => "skeleton-rs-pulse",
+            PulseMode::Color => "skeleton-rs-color-pulse",
+        }
+    }
+}
+
+/// Timing function preset of the animation.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Easing {
+    Linear,
+    EaseInOut,
+    EaseOut,
+    /// Slightly overshoots before settling.
+    Spring,
+}
+
+impl Easing {
+    /// Returns the CSS timing function of this preset.
+    pub fn timing_function(self) -> &'static str {
+        match self {
+            Easing::Linear => "linear",
+            Easing::EaseInOut => "ease-in-out",
+            Easing::EaseOut => "ease-out",
+            Easing::Spring => "cubic-bezier(0.34, 1.56, 0.64, 1)",
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Default)]
 pub enum Theme {
     #[default]
     Light,
     Dark,
     Custom(&'static str),
+    /// Custom base color computed at runtime, e.g. read from user settings or configuration.
+    CustomOwned(String),
+    /// Custom base color with an explicit highlight for the wave, shimmer, and gradient
+    /// animations, instead of the tint derived from the base.
+    CustomPair {
+        base: &'static str,
+        highlight: &'static str,
+    },
+    Named(&'static str),
+    /// Follows the OS light/dark color scheme preference.
+    System,
+}
+
+impl Theme {
+    /// Returns the base background color of the theme.
+    ///
+    /// This is re-derived on every render, so switching the theme at runtime updates the
+    /// skeleton without remounting it. `Theme::Named` resolves to the CSS variable of a
+    /// palette entry registered with `register_palette`, falling back to the light color.
+    pub fn base_color(&self) -> Cow<'static, str> {
+        match self {
+            Theme::Light => Cow::Borrowed("#e0e0e0"),
+            Theme::Dark => Cow::Borrowed("#444444"),
+            Theme::Custom(color) => Cow::Borrowed(color),
+            Theme::CustomOwned(color) => Cow::Owned(color.clone()),
+            Theme::CustomPair { base, .. } => Cow::Borrowed(base),
+            Theme::Named(name) => Cow::Owned(format!("var({}, #e0e0e0)", palette_var(name))),
+            Theme::System => Cow::Borrowed("#e0e0e0"),
+        }
+    }
+
+    /// Returns the name of the theme, rendered as the skeleton's `data-theme`.
+    ///
+    /// Every custom color theme is `"custom"`, and `Theme::Named` is its palette name.
+    ///
+    /// ```rust
+    /// use skeleton_rs::Theme;
+    ///
+    /// assert_eq!(Theme::Dark.as_str(), "dark");
+    /// assert_eq!(Theme::Custom("#0099ff").as_str(), "custom");
+    /// assert_eq!(Theme::Named("brand").as_str(), "brand");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+            Theme::Custom(_) | Theme::CustomOwned(_) | Theme::CustomPair { .. } => "custom",
+            Theme::Named(name) => name,
+            Theme::System => "system",
+        }
+    }
+
+    /// Returns the explicit highlight color of the theme, if any.
+    ///
+    /// Only `Theme::CustomPair` carries one; every other theme highlights with a lighter tint
+    /// of its base color, see `highlight_color`.
+    ///
+    /// ```rust
+    /// use skeleton_rs::Theme;
+    ///
+    /// let brand = Theme::CustomPair { base: "#1e3a8a", highlight: "#60a5fa" };
+    /// assert_eq!(brand.base_color(), "#1e3a8a");
+    /// assert_eq!(brand.highlight(), Some("#60a5fa"));
+    /// assert_eq!(Theme::Custom("#1e3a8a").highlight(), None);
+    /// ```
+    pub fn highlight(&self) -> Option<&'static str> {
+        match self {
+            Theme::CustomPair { highlight, .. } => Some(highlight),
+            _ => None,
+        }
+    }
+
+    /// Returns the base background color for the given color scheme preference.
+    ///
+    /// `Theme::System` resolves to the dark color when `prefers_dark` is set, and to the light
+    /// one otherwise; the other themes ignore the preference.
+    pub fn scheme_color(&self, prefers_dark: bool) -> Cow<'static, str> {
+        match self {
+            Theme::System if prefers_dark => Theme::Dark.base_color(),
+            Theme::System => Theme::Light.base_color(),
+            theme => theme.base_color(),
+        }
+    }
+}
+
+/// Error returned when a typed CSS value fails validation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssValueError {
+    /// The color is not a `#rgb`, `#rgba`, `#rrggbb`, or `#rrggbbaa` hex string.
+    InvalidColor(String),
+    /// The value is negative or not finite.
+    InvalidNumber(f32),
+}
+
+impl fmt::Display for CssValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CssValueError::InvalidColor(color) => write!(f, "invalid CSS hex color: {color:?}"),
+            CssValueError::InvalidNumber(value) => write!(f, "invalid CSS number: {value}"),
+        }
+    }
+}
+
+impl std::error::Error for CssValueError {}
+
+fn validate_number(value: f32) -> Result<f32, CssValueError> {
+    if value.is_finite() && value >= 0.0 {
+        Ok(value)
+    } else {
+        Err(CssValueError::InvalidNumber(value))
+    }
+}
+
+/// A validated CSS color.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssColor(String);
+
+impl CssColor {
+    /// Creates a color from a hex string such as `"#999"` or `"#0099ff"`.
+    pub fn hex(value: &str) -> Result<Self, CssValueError> {
+        let digits = value.strip_prefix('#').unwrap_or_default();
+        let valid =
+            matches!(digits.len(), 3 | 4 | 6 | 8) && digits.chars().all(|c| c.is_ascii_hexdigit());
+        if valid {
+            Ok(CssColor(value.to_string()))
+        } else {
+            Err(CssValueError::InvalidColor(value.to_string()))
+        }
+    }
+
+    /// Creates an opaque color from its red, green, and blue channels.
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        CssColor(format!("rgb({red}, {green}, {blue})"))
+    }
+}
+
+impl fmt::Display for CssColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A validated, non-negative CSS length.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CssLength {
+    value: f32,
+    unit: &'static str,
+}
+
+impl CssLength {
+    /// Creates a length in pixels.
+    pub fn px(value: f32) -> Result<Self, CssValueError> {
+        Self::new(value, "px")
+    }
+
+    /// Creates a length relative to the element's font size.
+    pub fn em(value: f32) -> Result<Self, CssValueError> {
+        Self::new(value, "em")
+    }
+
+    /// Creates a length relative to the root font size.
+    pub fn rem(value: f32) -> Result<Self, CssValueError> {
+        Self::new(value, "rem")
+    }
+
+    fn new(value: f32, unit: &'static str) -> Result<Self, CssValueError> {
+        validate_number(value).map(|value| CssLength { value, unit })
+    }
+}
+
+impl fmt::Display for CssLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit)
+    }
+}
+
+/// Outline drawn around a focused skeleton when `animate_on_focus` is enabled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusOutline {
+    pub width: CssLength,
+    pub color: CssColor,
+}
+
+impl fmt::Display for FocusOutline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} solid {}", self.width, self.color)
+    }
+}
+
+/// Filter applied to a hovered skeleton when `animate_on_hover` is enabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HoverFilter {
+    Brightness(f32),
+    Contrast(f32),
+    Saturate(f32),
+    Grayscale(f32),
+}
+
+impl HoverFilter {
+    /// Validates that the filter amount is finite and non-negative.
+    pub fn validated(self) -> Result<Self, CssValueError> {
+        let (HoverFilter::Brightness(amount)
+        | HoverFilter::Contrast(amount)
+        | HoverFilter::Saturate(amount)
+        | HoverFilter::Grayscale(amount)) = self;
+        validate_number(amount).map(|_| self)
+    }
+}
+
+impl fmt::Display for HoverFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HoverFilter::Brightness(amount) => write!(f, "brightness({amount})"),
+            HoverFilter::Contrast(amount) => write!(f, "contrast({amount})"),
+            HoverFilter::Saturate(amount) => write!(f, "saturate({amount})"),
+            HoverFilter::Grayscale(amount) => write!(f, "grayscale({amount})"),
+        }
+    }
+}
+
+/// Id of the `<style>` element holding the registered palette.
+pub const PALETTE_STYLE_ID: &str = "skeleton-rs-palette";
+
+/// Returns the CSS variable name of a palette entry, e.g. `--skeleton-brand`.
+///
+/// ```rust
+/// use skeleton_rs::Theme;
+/// use skeleton_rs::common::palette_var;
+///
+/// assert_eq!(palette_var("brand"), "--skeleton-brand");
+/// // A named theme reads the variable, falling back to the light color until registered.
+/// assert_eq!(Theme::Named("brand").base_color(), "var(--skeleton-brand, #e0e0e0)");
+/// ```
+pub fn palette_var(name: &str) -> String {
+    format!("--skeleton-{name}")
+}
+
+/// Builds the `:root` rule declaring the given palette entries as CSS variables.
+///
+/// ```rust
+/// use skeleton_rs::common::palette_css;
+///
+/// assert_eq!(palette_css(&[("brand", "#123")]), ":root { --skeleton-brand: #123; }");
+/// assert_eq!(
+///     palette_css(&[("brand", "#123"), ("muted", "#eee")]),
+///     ":root { --skeleton-brand: #123; --skeleton-muted: #eee; }"
+/// );
+/// ```
+pub fn palette_css(entries: &[(&str, &str)]) -> String {
+    let declarations: String = entries
+        .iter()
+        .map(|(name, color)| format!(" {}: {color};", palette_var(name)))
+        .collect();
+    format!(":root {{{declarations} }}")
+}
+
+/// Registers named palette colors as global CSS variables.
+///
+/// Each `(name, color)` pair is exposed as `--skeleton-{name}` and can be referenced by
+/// skeletons through `Theme::Named(name)`. The variables are injected into a single
+/// `<style>` element; calling this again replaces the previously registered palette.
+///
+/// Outside the browser, e.g. during server-side rendering, this does nothing.
+///
+/// ```rust
+/// use skeleton_rs::register_palette;
+///
+/// register_palette(&[("brand", "#123")]);
+/// ```
+pub fn register_palette(entries: &[(&str, &str)]) {
+    // Server-side rendering has no document to inject into.
+    if !cfg!(target_arch = "wasm32") {
+        return;
+    }
+    let Some(doc) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    let css = palette_css(entries);
+    if let Some(existing) = doc.get_element_by_id(PALETTE_STYLE_ID) {
+        existing.set_inner_html(&css);
+    } else if let (Ok(style_elem), Some(head)) = (doc.create_element("style"), doc.head()) {
+        style_elem.set_id(PALETTE_STYLE_ID);
+        apply_style_nonce(&style_elem);
+        style_elem.set_inner_html(&css);
+        let _ = head.append_child(&style_elem);
+    }
+}
+
+/// Token of the spacing scale, usable as `SkeletonGroup`'s gap.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Spacing {
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+impl Spacing {
+    fn name(self) -> &'static str {
+        match self {
+            Spacing::Xs => "xs",
+            Spacing::Sm => "sm",
+            Spacing::Md => "md",
+            Spacing::Lg => "lg",
+            Spacing::Xl => "xl",
+        }
+    }
+
+    /// Returns the CSS value of this token.
+    ///
+    /// Resolves to the variable declared by `register_spacing_scale`, falling back to the
+    /// default `SpacingScale` length when no scale has been registered.
+    pub fn css_value(self) -> &'static str {
+        match self {
+            Spacing::Xs => "var(--skeleton-space-xs, 4px)",
+            Spacing::Sm => "var(--skeleton-space-sm, 8px)",
+            Spacing::Md => "var(--skeleton-space-md, 16px)",
+            Spacing::Lg => "var(--skeleton-space-lg, 24px)",
+            Spacing::Xl => "var(--skeleton-space-xl, 32px)",
+        }
+    }
+}
+
+impl From<Spacing> for &'static str {
+    fn from(spacing: Spacing) -> Self {
+        spacing.css_value()
+    }
+}
+
+/// Main axis along which `SkeletonGroup` lays out its children.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GroupDirection {
+    Row,
+    Column,
+}
+
+impl GroupDirection {
+    /// Returns the `flex-direction` value of this direction.
+    pub fn css_value(self) -> &'static str {
+        match self {
+            GroupDirection::Row => "row",
+            GroupDirection::Column => "column",
+        }
+    }
+}
+
+/// Lengths of every `Spacing` token, mirroring the app's design tokens.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SpacingScale {
+    pub xs: &'static str,
+    pub sm: &'static str,
+    pub md: &'static str,
+    pub lg: &'static str,
+    pub xl: &'static str,
+}
+
+impl Default for SpacingScale {
+    fn default() -> Self {
+        Self {
+            xs: "4px",
+            sm: "8px",
+            md: "16px",
+            lg: "24px",
+            xl: "32px",
+        }
+    }
+}
+
+impl SpacingScale {
+    /// Returns the length mapped to the given token.
+    pub fn length(&self, spacing: Spacing) -> &'static str {
+        match spacing {
+            Spacing::Xs => self.xs,
+            Spacing::Sm => self.sm,
+            Spacing::Md => self.md,
+            Spacing::Lg => self.lg,
+            Spacing::Xl => self.xl,
+        }
+    }
+
+    /// Builds the `:root` rule declaring every token as a CSS variable.
+    pub fn css(&self) -> String {
+        let declarations: String = [
+            Spacing::Xs,
+            Spacing::Sm,
+            Spacing::Md,
+            Spacing::Lg,
+            Spacing::Xl,
+        ]
+        .into_iter()
+        .map(|spacing| {
+            format!(
+                " --skeleton-space-{}: {};",
+                spacing.name(),
+                self.length(spacing)
+            )
+        })
+        .collect();
+        format!(":root {{{declarations} }}")
+    }
+}
+
+/// Id of the `<style>` element holding the registered spacing scale.
+pub const SPACING_STYLE_ID: &str = "skeleton-rs-spacing";
+
+/// Registers the spacing scale resolved by `Spacing` tokens.
+///
+/// Each token is exposed as `--skeleton-space-{token}`, e.g. `--skeleton-space-md`. Like
+/// `register_palette`, calling this again replaces the previously registered scale.
+/// Outside the browser, e.g. during server-side rendering, this does nothing.
+///
+/// ```rust
+/// use skeleton_rs::{SpacingScale, register_spacing_scale};
+///
+/// register_spacing_scale(&SpacingScale::default());
+/// ```
+pub fn register_spacing_scale(scale: &SpacingScale) {
+    // Server-side rendering has no document to inject into.
+    if !cfg!(target_arch = "wasm32") {
+        return;
+    }
+    let Some(doc) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    let css = scale.css();
+    if let Some(existing) = doc.get_element_by_id(SPACING_STYLE_ID) {
+        existing.set_inner_html(&css);
+    } else if let (Ok(style_elem), Some(head)) = (doc.create_element("style"), doc.head()) {
+        style_elem.set_id(SPACING_STYLE_ID);
+        apply_style_nonce(&style_elem);
+        style_elem.set_inner_html(&css);
+        let _ = head.append_child(&style_elem);
+    }
+}
+
+/// Removes every stylesheet injected by skeleton components from the document.
+///
+/// Useful when an app tears down its loading placeholders entirely, e.g. when a setting
+/// disables loading animations. Stylesheets registered explicitly with `register_palette`
+/// and `register_spacing_scale` are kept, as is the `<noscript>` stylesheet rendered by
+/// `SkeletonProvider`, which belongs to the component tree. Skeletons mounted afterwards
+/// inject their styles again. Outside the browser there is nothing to remove and this
+/// does nothing.
+///
+/// ```rust
+/// skeleton_rs::remove_styles();
+/// ```
+pub fn remove_styles() {
+    use web_sys::wasm_bindgen::JsCast;
+
+    // Server-side rendering never injects stylesheets.
+    if !cfg!(target_arch = "wasm32") {
+        return;
+    }
+    let Some(doc) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    let Ok(styles) = doc.query_selector_all("style[id^='skeleton-rs-']") else {
+        return;
+    };
+    for index in 0..styles.length() {
+        let Some(style) = styles.item(index) else {
+            continue;
+        };
+        let Some(element) = style.dyn_ref::<web_sys::Element>() else {
+            continue;
+        };
+        let id = element.id();
+        if ![PALETTE_STYLE_ID, SPACING_STYLE_ID, NOSCRIPT_STYLE_ID].contains(&id.as_str()) {
+            element.remove();
+        }
+    }
 }
 
 #[derive(Clone, PartialEq, Default)]
@@ -35,3 +630,3056 @@ pub enum Direction {
     BottomToTop,
     CustomAngle(i64),
 }
+
+/// How the wave animation is rendered.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum WaveImpl {
+    /// Animates `background-position` of the skeleton's own gradient.
+    #[default]
+    BackgroundPosition,
+    /// Animates `transform` on an overlaid gradient element, which the browser can
+    /// composite on the GPU.
+    Transform,
+}
+
+impl Direction {
+    /// Returns the angle, in degrees, of the wave gradient for this direction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use skeleton_rs::Direction;
+    ///
+    /// assert_eq!(Direction::LeftToRight.gradient_angle(), 90);
+    /// assert_eq!(Direction::RightToLeft.gradient_angle(), 270);
+    /// assert_eq!(Direction::CustomAngle(45).gradient_angle(), 45);
+    /// ```
+    pub fn gradient_angle(&self) -> i64 {
+        match self {
+            Direction::LeftToRight => 90,
+            Direction::RightToLeft => 270,
+            Direction::TopToBottom => 180,
+            Direction::BottomToTop => 0,
+            Direction::CustomAngle(deg) => *deg,
+        }
+    }
+
+    /// Returns the name of the wave keyframes sweeping the gradient in this direction.
+    ///
+    /// Each sweep direction has its own keyframes, so skeletons waving in different
+    /// directions can share a page. Every custom angle gets its own keyframes too, named after
+    /// the angle normalized to `0..360`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use skeleton_rs::Direction;
+    ///
+    /// let ltr = Direction::LeftToRight;
+    /// let rtl = Direction::RightToLeft;
+    /// assert_eq!(ltr.wave_keyframes_name(), "skeleton-rs-wave-ltr");
+    /// assert_eq!(rtl.wave_keyframes_name(), "skeleton-rs-wave-rtl");
+    /// assert_eq!(Direction::CustomAngle(45).wave_keyframes_name(), "skeleton-rs-wave-a45");
+    /// assert_eq!(Direction::CustomAngle(-90).wave_keyframes_name(), "skeleton-rs-wave-a270");
+    ///
+    /// // Both keyframe blocks can be present at once, each under its own name.
+    /// let css = format!("{}{}", ltr.wave_keyframes(), rtl.wave_keyframes());
+    /// assert!(css.contains("@keyframes skeleton-rs-wave-ltr {"));
+    /// assert!(css.contains("@keyframes skeleton-rs-wave-rtl {"));
+    /// ```
+    pub fn wave_keyframes_name(&self) -> Cow<'static, str> {
+        match self {
+            Direction::LeftToRight => "skeleton-rs-wave-ltr".into(),
+            Direction::RightToLeft => "skeleton-rs-wave-rtl".into(),
+            Direction::TopToBottom => "skeleton-rs-wave-ttb".into(),
+            Direction::BottomToTop => "skeleton-rs-wave-btt".into(),
+            Direction::CustomAngle(deg) => {
+                format!("skeleton-rs-wave-a{}", deg.rem_euclid(360)).into()
+            }
+        }
+    }
+
+    /// Returns the `background-size` of the wave gradient for this direction.
+    ///
+    /// Custom angles sweep along both axes, so their gradient is oversized both ways.
+    pub fn wave_background_size(&self) -> &'static str {
+        match self {
+            Direction::CustomAngle(_) => "200% 200%",
+            _ => "200% 100%",
+        }
+    }
+
+    /// Returns the keyframes, named by `wave_keyframes_name`, sweeping the gradient in this
+    /// direction.
+    ///
+    /// Custom angles move the gradient along the angle's vector, so the highlight sweeps in
+    /// the direction the gradient is drawn, e.g. diagonally at 45°.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use skeleton_rs::Direction;
+    /// use skeleton_rs::common::wave_declaration;
+    ///
+    /// let rtl = Direction::RightToLeft.wave_keyframes();
+    /// assert!(rtl.contains("@keyframes skeleton-rs-wave-rtl"));
+    /// assert!(rtl.contains("0%   { background-position: calc(-200% + var(--skeleton-wave-offset, 0%)) 0; }"));
+    ///
+    /// // A 45° wave draws a 45° gradient and sweeps it diagonally, up and to the right.
+    /// let diagonal = Direction::CustomAngle(45);
+    /// let declaration = wave_declaration(&diagonal, "1.6s linear");
+    /// assert!(declaration.contains("linear-gradient(45deg"));
+    /// assert!(declaration.contains("animation: skeleton-rs-wave-a45 "));
+    /// let keyframes = diagonal.wave_keyframes();
+    /// assert!(keyframes.contains("@keyframes skeleton-rs-wave-a45"));
+    /// assert!(keyframes.contains(
+    ///     "0%   { background-position: calc(141.42% + var(--skeleton-wave-offset, 0%) * 0.7071) \
+    ///      calc(-141.42% + var(--skeleton-wave-offset, 0%) * -0.7071); }"
+    /// ));
+    /// assert_ne!(keyframes, Direction::LeftToRight.wave_keyframes());
+    /// ```
+    pub fn wave_keyframes(&self) -> Cow<'static, str> {
+        let keyframes = match self {
+            Direction::CustomAngle(deg) => return angled_wave_keyframes(*deg).into(),
+            Direction::LeftToRight => {
+                r#"
+                @keyframes skeleton-rs-wave-ltr {
+                    0%   { background-position: calc(200% + var(--skeleton-wave-offset, 0%)) 0; }
+                    25%  { background-position: calc(100% + var(--skeleton-wave-offset, 0%)) 0; }
+                    50%  { background-position: calc(0% + var(--skeleton-wave-offset, 0%)) 0; }
+                    75%  { background-position: calc(-100% + var(--skeleton-wave-offset, 0%)) 0; }
+                    100% { background-position: calc(-200% + var(--skeleton-wave-offset, 0%)) 0; }
+                }"#
+            }
+            Direction::RightToLeft => {
+                r#"
+                @keyframes skeleton-rs-wave-rtl {
+                    0%   { background-position: calc(-200% + var(--skeleton-wave-offset, 0%)) 0; }
+                    25%  { background-position: calc(-100% + var(--skeleton-wave-offset, 0%)) 0; }
+                    50%  { background-position: calc(0% + var(--skeleton-wave-offset, 0%)) 0; }
+                    75%  { background-position: calc(100% + var(--skeleton-wave-offset, 0%)) 0; }
+                    100% { background-position: calc(200% + var(--skeleton-wave-offset, 0%)) 0; }
+                }"#
+            }
+            Direction::TopToBottom => {
+                r#"
+                @keyframes skeleton-rs-wave-ttb {
+                    0%   { background-position: 0 calc(-200% + var(--skeleton-wave-offset, 0%)); }
+                    25%  { background-position: 0 calc(-100% + var(--skeleton-wave-offset, 0%)); }
+                    50%  { background-position: 0 calc(0% + var(--skeleton-wave-offset, 0%)); }
+                    75%  { background-position: 0 calc(100% + var(--skeleton-wave-offset, 0%)); }
+                    100% { background-position: 0 calc(200% + var(--skeleton-wave-offset, 0%)); }
+                }"#
+            }
+            Direction::BottomToTop => {
+                r#"
+                @keyframes skeleton-rs-wave-btt {
+                    0%   { background-position: 0 calc(200% + var(--skeleton-wave-offset, 0%)); }
+                    25%  { background-position: 0 calc(100% + var(--skeleton-wave-offset, 0%)); }
+                    50%  { background-position: 0 calc(0% + var(--skeleton-wave-offset, 0%)); }
+                    75%  { background-position: 0 calc(-100% + var(--skeleton-wave-offset, 0%)); }
+                    100% { background-position: 0 calc(-200% + var(--skeleton-wave-offset, 0%)); }
+                }"#
+            }
+        };
+        keyframes.into()
+    }
+
+    /// Returns the name of the shimmer keyframes moving the band in this direction.
+    ///
+    /// The band is drawn at `gradient_angle` and travels along both axes, so it crosses the
+    /// skeleton at any angle. Custom angles travel forward like left to right.
+    ///
+    /// ```rust
+    /// use skeleton_rs::Direction;
+    ///
+    /// assert_eq!(Direction::LeftToRight.shimmer_keyframes_name(), "skeleton-rs-shimmer");
+    /// assert_eq!(Direction::BottomToTop.shimmer_keyframes_name(), "skeleton-rs-shimmer-reverse");
+    /// ```
+    pub fn shimmer_keyframes_name(&self) -> &'static str {
+        match self {
+            Direction::LeftToRight | Direction::TopToBottom | Direction::CustomAngle(_) => {
+                "skeleton-rs-shimmer"
+            }
+            Direction::RightToLeft | Direction::BottomToTop => "skeleton-rs-shimmer-reverse",
+        }
+    }
+
+    /// Returns the name of the transform-based wave keyframes for this direction.
+    ///
+    /// Custom angles sweep left to right; the gradient itself is drawn at the angle.
+    pub fn transform_wave_keyframes(&self) -> &'static str {
+        match self {
+            Direction::LeftToRight | Direction::CustomAngle(_) => "skeleton-rs-wave-tx-ltr",
+            Direction::RightToLeft => "skeleton-rs-wave-tx-rtl",
+            Direction::TopToBottom => "skeleton-rs-wave-tx-ttb",
+            Direction::BottomToTop => "skeleton-rs-wave-tx-btt",
+        }
+    }
+}
+
+// Mirrors the fixed directions: the gradient travels 400% of its position range along the
+// angle's unit vector, `(sin θ, -cos θ)` on screen, so 90° matches the left-to-right wave.
+fn angled_wave_keyframes(deg: i64) -> String {
+    let radians = (deg.rem_euclid(360) as f64).to_radians();
+    // Rounding and adding zero keeps the output free of `-0.00`.
+    let round = |value: f64| (value * 10_000.0).round() / 10_000.0 + 0.0;
+    let (dx, dy) = (round(radians.sin()), round(-radians.cos()));
+    let steps: String = [(0, 200.0), (25, 100.0), (50, 0.0), (75, -100.0), (100, -200.0)]
+        .iter()
+        .map(|(percent, position)| {
+            let key = format!("{percent}%");
+            format!(
+                "\n    {key:<4} {{ background-position: calc({x:.2}% + var(--skeleton-wave-offset, 0%) * {dx}) calc({y:.2}% + var(--skeleton-wave-offset, 0%) * {dy}); }}",
+                x = round(position * dx),
+                y = round(position * dy),
+            )
+        })
+        .collect();
+    format!(
+        "\n@keyframes skeleton-rs-wave-a{} {{{steps}\n}}",
+        deg.rem_euclid(360)
+    )
+}
+
+/// Keyframes of the pulse animation, shared by every backend.
+///
+/// The lowest opacity is read from the `--skeleton-rs-pulse-min` variable, set by
+/// `pulse_min_opacity`, and defaults to `DEFAULT_PULSE_MIN_OPACITY`; the quarter stops sit
+/// halfway between it and full opacity.
+///
+/// It is the only definition of `skeleton-rs-pulse`: every stylesheet a backend injects or
+/// renders embeds it verbatim, so `Animation::Pulse` looks the same in each framework.
+/// Scoped styles embed it too, under an instance-suffixed name.
+///
+/// ```rust
+/// use skeleton_rs::common::{
+///     AttachedSkeleton, DEFAULT_PULSE_TIMING, PULSE_KEYFRAMES, PulseMode, base_stylesheet,
+///     noscript_shared_css, pulse_declaration, scoped_animation_css,
+/// };
+/// use skeleton_rs::skeleton_styles;
+///
+/// let (_, attached) = AttachedSkeleton::default().stylesheet();
+/// for css in [
+///     base_stylesheet(),
+///     skeleton_styles().to_string(),
+///     PulseMode::Opacity.keyframes().to_string(),
+///     noscript_shared_css(),
+///     attached,
+/// ] {
+///     assert_eq!(css.matches("@keyframes skeleton-rs-pulse {").count(), 1);
+///     assert!(css.contains(PULSE_KEYFRAMES));
+/// }
+///
+/// let declaration = pulse_declaration("skeleton-rs-pulse", DEFAULT_PULSE_TIMING);
+/// let (_, scoped) = scoped_animation_css(3, PULSE_KEYFRAMES, &declaration);
+/// assert!(scoped.contains(&PULSE_KEYFRAMES.replace("skeleton-rs-pulse {", "skeleton-rs-pulse-3 {")));
+/// ```
+pub const PULSE_KEYFRAMES: &str = "@keyframes skeleton-rs-pulse {
+    0% { opacity: 1; }
+    25% { opacity: calc((1 + var(--skeleton-rs-pulse-min, 0.4)) / 2); }
+    50% { opacity: var(--skeleton-rs-pulse-min, 0.4); }
+    75% { opacity: calc((1 + var(--skeleton-rs-pulse-min, 0.4)) / 2); }
+    100% { opacity: 1; }
+}";
+
+/// Lowest opacity of the pulse animation unless `pulse_min_opacity` says otherwise.
+pub const DEFAULT_PULSE_MIN_OPACITY: f32 = 0.4;
+
+/// Clamps a `pulse_min_opacity` to the valid `0.0..=1.0` range.
+///
+/// Non-finite values fall back to `DEFAULT_PULSE_MIN_OPACITY`.
+///
+/// ```rust
+/// use skeleton_rs::common::{DEFAULT_PULSE_MIN_OPACITY, clamp_opacity};
+///
+/// assert_eq!(clamp_opacity(0.2), 0.2);
+/// assert_eq!(clamp_opacity(-0.5), 0.0);
+/// assert_eq!(clamp_opacity(3.0), 1.0);
+/// assert_eq!(clamp_opacity(f32::NAN), DEFAULT_PULSE_MIN_OPACITY);
+/// ```
+pub fn clamp_opacity(opacity: f32) -> f32 {
+    if opacity.is_finite() {
+        opacity.clamp(0.0, 1.0)
+    } else {
+        DEFAULT_PULSE_MIN_OPACITY
+    }
+}
+
+/// Returns the highlight of gradient animations, a lighter tint of the `base` color.
+///
+/// Dark bases get a slightly lighter gray rather than the light theme's near-white highlight.
+/// A `--skeleton-rs-highlight` variable, set by `Theme::CustomPair`, takes precedence over
+/// the tint.
+pub fn highlight_color(base: &str) -> String {
+    format!("var(--skeleton-rs-highlight, color-mix(in srgb, {base}, #fff 35%))")
+}
+
+/// Highlight of the shimmer overlay sliding over checkerboard, outline, and transform waves.
+///
+/// Like the gradients, it reads the `--skeleton-rs-highlight` variable, so a theme change
+/// restyles the overlay through the skeleton's inline style alone.
+pub const OVERLAY_HIGHLIGHT: &str = "var(--skeleton-rs-highlight, rgba(255, 255, 255, 0.4))";
+
+/// Returns the gradient of wave and gradient-pulse animations.
+///
+/// Both the stops and the highlight derive from `base`, which components pass as the
+/// `--skeleton-rs-base` variable, so the shimmer follows the theme like the pulse does.
+pub fn shimmer_gradient(angle: i64, base: &str) -> String {
+    let highlight = highlight_color(base);
+    format!("linear-gradient({angle}deg, {base} 25%, {highlight} 50%, {base} 75%)")
+}
+
+/// Returns the gradient of the shimmer animation: a narrow band, brighter than the wave's
+/// highlight, over the `base` color.
+///
+/// Like `highlight_color`, the band takes the `--skeleton-rs-highlight` variable when set.
+pub fn shimmer_band_gradient(angle: i64, base: &str) -> String {
+    format!(
+        "linear-gradient({angle}deg, {base} 45%, var(--skeleton-rs-highlight, color-mix(in srgb, {base}, #fff 60%)) 50%, {base} 55%)"
+    )
+}
+
+/// Keyframes of the shimmer animation, forward and reverse.
+///
+/// The band gradient is three times the size of the skeleton on both axes, so moving its
+/// position from one corner to the other carries the band fully across at any angle.
+pub const SHIMMER_KEYFRAMES: &str = "@keyframes skeleton-rs-shimmer {
+    0% { background-position: 100% 100%; }
+    100% { background-position: 0% 0%; }
+}
+@keyframes skeleton-rs-shimmer-reverse {
+    0% { background-position: 0% 0%; }
+    100% { background-position: 100% 100%; }
+}";
+
+/// Keyframes of the glow animation, pulsing a `box-shadow` from a subtle to a stronger
+/// spread.
+///
+/// The shadow takes the `--skeleton-rs-base` color, or the `--skeleton-rs-highlight`
+/// variable when set, and follows the element's `border-radius`, so circular variants glow
+/// as circles.
+pub const GLOW_KEYFRAMES: &str = "@keyframes skeleton-rs-glow {
+    0%, 100% { box-shadow: 0 0 2px 0 var(--skeleton-rs-highlight, var(--skeleton-rs-base)); }
+    50% { box-shadow: 0 0 12px 4px var(--skeleton-rs-highlight, var(--skeleton-rs-base)); }
+}";
+
+/// Keyframes of `PulseMode::Color`, fading the base color towards a lighter tint, or towards
+/// the `--skeleton-rs-highlight` variable when set.
+///
+/// Only `background-color` changes, so the skeleton stays fully opaque.
+pub const COLOR_PULSE_KEYFRAMES: &str = "@keyframes skeleton-rs-color-pulse {
+    0%, 100% { background-color: var(--skeleton-rs-base); }
+    50% { background-color: var(--skeleton-rs-highlight, color-mix(in srgb, var(--skeleton-rs-base), #fff 35%)); }
+}";
+
+/// Id of the `<noscript>` stylesheet rendered once by `SkeletonProvider`.
+pub const NOSCRIPT_STYLE_ID: &str = "skeleton-rs-noscript";
+
+/// Builds the stylesheet `SkeletonProvider` renders once inside `<noscript>`: the pulse
+/// keyframes and the default pulse class, shared by every skeleton below it.
+///
+/// Without JavaScript no stylesheet is injected at runtime, so the keyframes are shipped
+/// with the markup itself. Browsers with scripting enabled ignore `<noscript>` content,
+/// which keeps hydration consistent.
+pub fn noscript_shared_css() -> String {
+    format!(
+        "{PULSE_KEYFRAMES} {COLOR_PULSE_KEYFRAMES} .{PULSE_CLASS} {{ {} }}",
+        default_pulse_declaration()
+    )
+}
+
+/// Builds the stylesheet a pulse skeleton renders inside `<noscript>`.
+///
+/// Under a provider, which already ships `noscript_shared_css`, only a rule for a
+/// generated class is needed; a skeleton without one carries the keyframes itself.
+///
+/// ```rust
+/// use skeleton_rs::common::{PULSE_CLASS, noscript_pulse_css};
+///
+/// let rule = ".skeleton-rs-anim-1 { animation: skeleton-rs-pulse 2s infinite; }";
+/// assert_eq!(noscript_pulse_css(PULSE_CLASS, "", true), None);
+/// assert_eq!(
+///     noscript_pulse_css("skeleton-rs-anim-1", "animation: skeleton-rs-pulse 2s infinite;", true),
+///     Some(rule.to_string())
+/// );
+/// assert!(noscript_pulse_css(PULSE_CLASS, "", false).unwrap().contains("@keyframes"));
+/// ```
+pub fn noscript_pulse_css(anim_class: &str, declaration: &str, shared: bool) -> Option<String> {
+    let rule = format!(".{anim_class} {{ {declaration} }}");
+    match (shared, is_preset_animation_class(anim_class)) {
+        (false, _) => Some(format!("{PULSE_KEYFRAMES} {COLOR_PULSE_KEYFRAMES} {rule}")),
+        (true, true) => None,
+        (true, false) => Some(rule),
+    }
+}
+
+/// Two-tone checkerboard background of `Variant::Checkerboard`.
+///
+/// The second tone darkens the theme's base color, so both grays follow the theme.
+pub const CHECKERBOARD_STYLE: &str = " background-image: conic-gradient(rgba(0, 0, 0, 0.08) 25%, \
+     transparent 0 50%, rgba(0, 0, 0, 0.08) 0 75%, transparent 0); background-size: 16px 16px;";
+
+/// Builds the stylesheet shared by every skeleton, injected once per document.
+///
+/// Holds the keyframes of the built-in animations, the default pulse and wave classes, and
+/// the rules of the behavior classes (hover, focus, group layouts, reduced motion, print,
+/// ...). Wave keyframes are not included: each waving skeleton injects those of its own
+/// direction, keyed by `Direction::wave_keyframes_name`.
+pub fn base_stylesheet() -> String {
+    let pulse = default_pulse_declaration();
+    let wave = default_wave_declaration();
+    format!(
+        r#"
+        {PULSE_KEYFRAMES}
+
+        {COLOR_PULSE_KEYFRAMES}
+
+        {SHIMMER_KEYFRAMES}
+
+        {GLOW_KEYFRAMES}
+
+        {TRANSFORM_WAVE_KEYFRAMES}
+
+        {REFRESHING_STYLE}
+
+        {REVEAL_STYLE}
+
+        .{PULSE_CLASS} {{
+            {pulse}
+        }}
+
+        .{WAVE_CLASS} {{
+            {wave}
+        }}
+
+        .skeleton-hover:hover {{
+            filter: var(--skeleton-hover-filter, brightness(0.95));
+        }}
+
+        .skeleton-pause-on-hover:hover,
+        .skeleton-pause-on-hover:hover > span {{
+            animation-play-state: paused;
+        }}
+
+        .skeleton-focus:focus {{
+            outline: var(--skeleton-focus-outline, 2px solid #999);
+        }}
+
+        .skeleton-active:active {{
+            transform: scale(0.98);
+        }}
+
+        .skeleton-group-overlap > * + :not(.skeleton-group-ignore) {{
+            margin-left: calc(-1 * var(--skeleton-group-overlap));
+        }}
+
+        .skeleton-group-logical > * + :not(.skeleton-group-ignore) {{
+            margin-left: 0;
+            margin-inline-start: calc(-1 * var(--skeleton-group-overlap));
+        }}
+
+        .skeleton-group-striped > :nth-child(odd of :not(.skeleton-group-ignore)) {{
+            --skeleton-rs-base: var(--skeleton-group-stripe-odd) !important;
+        }}
+
+        .skeleton-group-striped > :nth-child(even of :not(.skeleton-group-ignore)) {{
+            --skeleton-rs-base: var(--skeleton-group-stripe-even) !important;
+        }}
+
+        @container (max-width: 480px) {{
+            .skeleton-container-responsive {{
+                width: 100% !important;
+                max-width: 100% !important;
+            }}
+        }}
+
+        @media (prefers-reduced-motion: reduce) {{
+            .skeleton-reduced-motion,
+            .skeleton-reduced-motion * {{
+                animation: none !important;
+            }}
+        }}
+
+        @media print {{
+            .skeleton-rs {{
+                animation: none !important;
+                background: #f0f0f0 !important;
+            }}
+
+            .skeleton-print-hidden {{
+                display: none !important;
+            }}
+        }}
+        "#
+    )
+}
+
+/// Returns the static stylesheet of every skeleton, for server-side rendering.
+///
+/// Holds the base stylesheet and the wave keyframes of every direction. Inline it into the
+/// `<head>` of the server-rendered page as `<style id="skeleton-rs-style">`, so the first
+/// paint is already animated; skeletons find the element by its id and do not inject the
+/// base stylesheet again. With the `static-styles` feature enabled, skeletons also skip the
+/// per-direction wave keyframes, leaving only classes for non-default animation parameters
+/// to be injected at runtime.
+///
+/// ```rust
+/// use skeleton_rs::skeleton_styles;
+///
+/// let head = format!(r#"<style id="skeleton-rs-style">{}</style>"#, skeleton_styles());
+/// assert!(head.contains("@keyframes skeleton-rs-pulse"));
+/// assert!(head.contains("@keyframes skeleton-rs-wave-btt"));
+/// ```
+pub fn skeleton_styles() -> &'static str {
+    static STYLES: OnceLock<String> = OnceLock::new();
+    STYLES.get_or_init(|| {
+        let mut css = base_stylesheet();
+        for direction in [
+            Direction::LeftToRight,
+            Direction::RightToLeft,
+            Direction::TopToBottom,
+            Direction::BottomToTop,
+        ] {
+            css.push_str(&direction.wave_keyframes());
+        }
+        css
+    })
+}
+
+/// Returns the static stylesheet of skeletons waving in `direction`, for CSS bundlers.
+///
+/// Holds the base stylesheet, with the keyframes and classes of every built-in animation,
+/// plus the wave keyframes of `direction`, including those of a `CustomAngle` that
+/// `skeleton_styles` does not cover. Apps that ship their own CSS pipeline can write it to a
+/// file at build time and disable runtime injection with the `no-runtime-style` feature.
+///
+/// ```rust
+/// use skeleton_rs::{Direction, skeleton_css};
+///
+/// let css = skeleton_css(&Direction::CustomAngle(45));
+/// assert!(css.contains("@keyframes skeleton-rs-pulse"));
+/// assert!(css.contains("@keyframes skeleton-rs-wave-a45"));
+/// assert!(!css.contains("@keyframes skeleton-rs-wave-ltr"));
+/// ```
+pub fn skeleton_css(direction: &Direction) -> String {
+    let mut css = base_stylesheet();
+    css.push_str(&direction.wave_keyframes());
+    css
+}
+
+/// Returns whether skeletons inject stylesheets at runtime, which the `no-runtime-style`
+/// feature disables.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn runtime_styles_enabled() -> bool {
+    !cfg!(feature = "no-runtime-style")
+}
+
+/// Returns the animation declarations to inline on a skeleton whose generated `anim_class`
+/// has no stylesheet, prefixed with a space.
+///
+/// Without runtime styles, only the preset classes of the bundled base stylesheet have
+/// rules; any other animation is inlined instead. Empty otherwise.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn inline_animation(anim_class: Option<&str>, declaration: &str) -> String {
+    match anim_class {
+        Some(class) if !runtime_styles_enabled() && !is_preset_animation_class(class) => {
+            format!(" {declaration}")
+        }
+        _ => String::new(),
+    }
+}
+
+/// Returns whether the shared stylesheets are expected to be inlined by the server, see
+/// `skeleton_styles`.
+///
+/// Only applies to the document itself: a shadow root never sees the page's `<head>`.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn uses_static_styles(shadow_host: Option<&str>) -> bool {
+    cfg!(feature = "static-styles") && shadow_host.is_none()
+}
+
+/// Keeps the children laid out but invisible while `infer_size` measures them.
+pub const INFER_SIZE_PROBE_STYLE: &str =
+    "position: absolute; visibility: hidden; pointer-events: none;";
+
+/// Visually hides an element while keeping it readable by assistive technology.
+pub const SR_ONLY_STYLE: &str = "position: absolute; width: 1px; height: 1px; padding: 0; \
+     margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;";
+
+/// Layout of the container of a `Variant::Quote` placeholder.
+///
+/// The container itself is transparent; only the blocks returned by `quote_blocks` are drawn.
+pub const QUOTE_LAYOUT: &str = " display: grid; grid-template-columns: 3em 1fr; gap: 0.5em 0.75em; \
+     align-items: center; background: transparent;";
+
+/// Returns the inline styles of the blocks composing a `Variant::Quote` placeholder.
+///
+/// An avatar and a short name bar come first, followed by `lines` full-width quote lines,
+/// the last of which is shorter, like the end of a paragraph.
+pub fn quote_blocks(lines: usize) -> Vec<String> {
+    let block = "display: block; background-color: var(--skeleton-rs-base);";
+    let mut blocks = vec![
+        format!("{block} width: 3em; height: 3em; border-radius: 50%;"),
+        format!("{block} width: 40%; height: 1em; border-radius: 4px;"),
+    ];
+    blocks.extend((0..lines).map(|line| {
+        let width = if line + 1 == lines && lines > 1 {
+            "60%"
+        } else {
+            "100%"
+        };
+        format!("{block} grid-column: 1 / -1; width: {width}; height: 0.8em; border-radius: 4px;")
+    }));
+    blocks
+}
+
+/// Layout of the container of a `Variant::Card` placeholder.
+///
+/// The container itself is transparent; only the blocks returned by `card_blocks` are drawn.
+pub const CARD_LAYOUT: &str =
+    " display: flex; flex-direction: column; gap: 0.75em; background: transparent;";
+
+/// Returns the inline styles of the blocks composing a `Variant::Card` placeholder.
+///
+/// An image area rounded by `border_radius` comes first, followed by a short title bar and two text lines. The
+/// image keeps a 16:9 ratio when the card's height is automatic, and otherwise fills the
+/// height left over by the text.
+pub fn card_blocks(border_radius: &str) -> Vec<String> {
+    let block = "display: block; background-color: var(--skeleton-rs-base); flex: none;";
+    vec![
+        format!(
+            "display: block; background-color: var(--skeleton-rs-base); flex: 1 1 auto; min-height: 0; width: 100%; aspect-ratio: 16 / 9; border-radius: {border_radius};"
+        ),
+        format!("{block} width: 60%; height: 1.25em; border-radius: 4px;"),
+        format!("{block} width: 100%; height: 0.8em; border-radius: 4px;"),
+        format!("{block} width: 80%; height: 0.8em; border-radius: 4px;"),
+    ]
+}
+
+/// Returns the container layout and the cell styles of a `Variant::Table` placeholder.
+///
+/// The cells form a grid of `rows` by `columns`, the first row being a slightly taller
+/// header. Each cell is rounded by `border_radius` and drawn in the skeleton's base color, so
+/// the theme and animation apply to every cell. With zero `rows` or `columns` the table
+/// collapses to an empty, transparent container.
+///
+/// ```rust
+/// use skeleton_rs::common::table_layout;
+///
+/// let (layout, cells) = table_layout(5, 4, "4px");
+/// assert!(layout.contains("grid-template-columns: repeat(4, minmax(0, 1fr));"));
+/// assert_eq!(cells.len(), 20);
+/// assert!(cells[0].contains("height: 1.2em;"));
+/// assert!(cells[4].contains("height: 0.8em;"));
+///
+/// let (_, cells) = table_layout(0, 4, "4px");
+/// assert!(cells.is_empty());
+/// ```
+pub fn table_layout(rows: usize, columns: usize, border_radius: &str) -> (String, Vec<String>) {
+    if rows == 0 || columns == 0 {
+        return (" background: transparent;".to_string(), Vec::new());
+    }
+    let layout = format!(
+        " display: grid; grid-template-columns: repeat({columns}, minmax(0, 1fr)); gap: 0.75em 1em; align-items: center; background: transparent;"
+    );
+    let cells = (0..rows)
+        .flat_map(|row| {
+            let height = if row == 0 { "1.2em" } else { "0.8em" };
+            (0..columns).map(move |_| {
+                format!(
+                    "display: block; background-color: var(--skeleton-rs-base); width: 100%; height: {height}; border-radius: {border_radius};"
+                )
+            })
+        })
+        .collect();
+    (layout, cells)
+}
+
+/// Layout of the row of a `SkeletonListItem`: the avatar beside the text lines.
+pub const LIST_ITEM_LAYOUT: &str = "display: flex; align-items: center; gap: 0.75em;";
+
+/// Lets the text lines of a `SkeletonListItem` take the width left by the avatar.
+pub const LIST_ITEM_TEXT_STYLE: &str = " flex: 1; min-width: 0;";
+
+/// Error returned when a layout template fails to parse.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateError {
+    /// The template, or one of its `|`-separated columns, contains no shape.
+    Empty,
+    /// The shape name is not one of `avatar`, `line`, `block`, or `button`.
+    UnknownShape(String),
+    /// The argument list of the shape is missing its closing parenthesis.
+    UnclosedParen(String),
+    /// The shape was given the wrong number of arguments.
+    InvalidArguments(String),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::Empty => write!(f, "template column has no shapes"),
+            TemplateError::UnknownShape(name) => write!(f, "unknown template shape: {name:?}"),
+            TemplateError::UnclosedParen(name) => {
+                write!(f, "missing `)` after the arguments of {name:?}")
+            }
+            TemplateError::InvalidArguments(name) => {
+                write!(f, "wrong number of arguments for {name:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+/// A shape of a layout template.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateShape {
+    /// `avatar` or `avatar(size)`: a circle, `3em` wide by default.
+    Avatar(String),
+    /// `line` or `line(width)`: a text bar, full width by default.
+    Line(String),
+    /// `block(width, height)`: a rounded rectangle.
+    Block(String, String),
+    /// `button`: a button-sized rounded rectangle.
+    Button,
+}
+
+impl TemplateShape {
+    fn parse(name: &str, args: Option<&str>) -> Result<Self, TemplateError> {
+        let args: Vec<&str> = args
+            .map(|args| args.split(',').map(str::trim).collect())
+            .unwrap_or_default();
+        let invalid = || TemplateError::InvalidArguments(name.to_string());
+        match (name, args.as_slice()) {
+            ("avatar", []) => Ok(TemplateShape::Avatar("3em".to_string())),
+            ("avatar", [size]) if !size.is_empty() => Ok(TemplateShape::Avatar(size.to_string())),
+            ("line", []) => Ok(TemplateShape::Line("100%".to_string())),
+            ("line", [width]) if !width.is_empty() => Ok(TemplateShape::Line(width.to_string())),
+            ("block", [width, height]) if !width.is_empty() && !height.is_empty() => {
+                Ok(TemplateShape::Block(width.to_string(), height.to_string()))
+            }
+            ("button", []) => Ok(TemplateShape::Button),
+            ("avatar" | "line" | "block" | "button", _) => Err(invalid()),
+            _ => Err(TemplateError::UnknownShape(name.to_string())),
+        }
+    }
+
+    fn style(&self) -> String {
+        match self {
+            TemplateShape::Avatar(size) => {
+                format!("width: {size}; height: {size}; border-radius: 50%;")
+            }
+            TemplateShape::Line(width) => {
+                format!("width: {width}; height: 0.8em; border-radius: 4px;")
+            }
+            TemplateShape::Block(width, height) => {
+                format!("width: {width}; height: {height}; border-radius: 4px;")
+            }
+            TemplateShape::Button => "width: 6em; height: 2.25em; border-radius: 6px;".to_string(),
+        }
+    }
+}
+
+fn parse_template_column(column: &str) -> Result<Vec<TemplateShape>, TemplateError> {
+    let mut shapes = Vec::new();
+    let mut rest = column.trim_start();
+    while !rest.is_empty() {
+        let name_len = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        if name_len == 0 {
+            let token = rest.split_whitespace().next().unwrap_or(rest);
+            return Err(TemplateError::UnknownShape(token.to_string()));
+        }
+        let (name, after) = rest.split_at(name_len);
+        let (args, after) = match after.strip_prefix('(') {
+            Some(inner) => {
+                let end = inner
+                    .find(')')
+                    .ok_or_else(|| TemplateError::UnclosedParen(name.to_string()))?;
+                (Some(&inner[..end]), &inner[end + 1..])
+            }
+            None => (None, after),
+        };
+        shapes.push(TemplateShape::parse(name, args)?);
+        rest = after.trim_start();
+    }
+    if shapes.is_empty() {
+        return Err(TemplateError::Empty);
+    }
+    Ok(shapes)
+}
+
+/// Parses a layout template into columns of shapes.
+///
+/// Columns are separated by `|` and laid out side by side; the shapes of a column are
+/// separated by whitespace and stacked vertically.
+///
+/// # Examples
+///
+/// ```rust
+/// use skeleton_rs::common::{TemplateError, TemplateShape, parse_template};
+///
+/// let columns = parse_template("avatar | line(80%) line(60%)").unwrap();
+/// assert_eq!(columns[0], vec![TemplateShape::Avatar("3em".to_string())]);
+/// assert_eq!(columns[1][1], TemplateShape::Line("60%".to_string()));
+///
+/// let columns = parse_template("block(100%, 8em) line button").unwrap();
+/// assert_eq!(columns[0].len(), 3);
+///
+/// assert_eq!(parse_template("  "), Err(TemplateError::Empty));
+/// assert_eq!(parse_template("avatar |"), Err(TemplateError::Empty));
+/// assert_eq!(
+///     parse_template("circle"),
+///     Err(TemplateError::UnknownShape("circle".to_string()))
+/// );
+/// assert_eq!(
+///     parse_template("line(80%"),
+///     Err(TemplateError::UnclosedParen("line".to_string()))
+/// );
+/// assert_eq!(
+///     parse_template("block(4em)"),
+///     Err(TemplateError::InvalidArguments("block".to_string()))
+/// );
+/// ```
+pub fn parse_template(template: &str) -> Result<Vec<Vec<TemplateShape>>, TemplateError> {
+    template.split('|').map(parse_template_column).collect()
+}
+
+/// Returns the container layout and block styles rendering parsed template columns.
+///
+/// Columns become grid tracks: columns holding only avatars and buttons shrink to fit,
+/// the others share the remaining width. A column with a single shape spans every row and
+/// is centered vertically.
+pub fn template_layout(columns: &[Vec<TemplateShape>]) -> (String, Vec<String>) {
+    let rows = columns.iter().map(Vec::len).max().unwrap_or(1);
+    let tracks: Vec<&str> = columns
+        .iter()
+        .map(|column| {
+            let fixed = column
+                .iter()
+                .all(|shape| matches!(shape, TemplateShape::Avatar(_) | TemplateShape::Button));
+            if fixed { "auto" } else { "1fr" }
+        })
+        .collect();
+    let layout = format!(
+        " display: grid; grid-template-columns: {}; gap: 0.5em 0.75em; align-items: center; background: transparent;",
+        tracks.join(" ")
+    );
+    let blocks = columns
+        .iter()
+        .enumerate()
+        .flat_map(|(column_index, column)| {
+            column.iter().enumerate().map(move |(row_index, shape)| {
+                let row = if column.len() == 1 {
+                    format!("1 / span {rows}")
+                } else {
+                    (row_index + 1).to_string()
+                };
+                format!(
+                    "display: block; background-color: var(--skeleton-rs-base); grid-column: {}; grid-row: {row}; {}",
+                    column_index + 1,
+                    shape.style()
+                )
+            })
+        })
+        .collect();
+    (layout, blocks)
+}
+
+/// Returns the layout of the container of a multi-line `Variant::Text` placeholder.
+///
+/// Like `QUOTE_LAYOUT`, the container itself is transparent and only its bars are drawn.
+pub fn text_lines_layout(gap: &str) -> String {
+    format!(" display: flex; flex-direction: column; gap: {gap}; background: transparent;")
+}
+
+/// Returns the inline styles of the bars of a multi-line `Variant::Text` placeholder.
+///
+/// `lines` full-width bars of the given `height` are stacked; the last one is
+/// `last_line_width` wide, mimicking the end of a paragraph. With a `line_box`, each bar is
+/// vertically centered in a line box of that height, like glyphs in a line of text, so the
+/// paragraph is as tall as the text it stands in for.
+///
+/// ```rust
+/// use skeleton_rs::common::text_line_blocks;
+///
+/// // A paragraph of 0.8em glyphs with `line-height: 1.5em`.
+/// let blocks = text_line_blocks(3, "0.8em", Some("1.5em"), "60%", "4px");
+/// assert!(blocks[0].ends_with(" margin-block: calc((1.5em - 0.8em) / 2);"));
+/// assert!(blocks[2].contains("width: 60%;"));
+/// ```
+pub fn text_line_blocks(
+    lines: usize,
+    height: &str,
+    line_box: Option<&str>,
+    last_line_width: &str,
+    border_radius: &str,
+) -> Vec<String> {
+    let margin = line_box.map_or_else(String::new, |line_box| {
+        format!(" margin-block: calc(({line_box} - {height}) / 2);")
+    });
+    (0..lines)
+        .map(|line| {
+            let width = if line + 1 == lines {
+                last_line_width
+            } else {
+                "100%"
+            };
+            format!(
+                "display: block; background-color: var(--skeleton-rs-base); width: {width}; height: {height}; border-radius: {border_radius};{margin}"
+            )
+        })
+        .collect()
+}
+
+/// Returns the inline styles of the bars of a `Variant::Paragraph` placeholder.
+///
+/// A full-width heading bar, 1.75 times the body `height`, comes first, followed by the
+/// `lines` body lines of `text_line_blocks`, so the body is spaced exactly like a multi-line
+/// `Variant::Text`.
+///
+/// ```rust
+/// use skeleton_rs::common::paragraph_blocks;
+///
+/// let blocks = paragraph_blocks(3, "0.8em", None, "60%", "4px");
+/// assert_eq!(blocks.len(), 4);
+/// assert!(blocks[0].contains("height: calc(0.8em * 1.75);"));
+/// assert!(blocks[1].contains("height: 0.8em;"));
+/// assert!(blocks[3].contains("width: 60%;"));
+/// ```
+pub fn paragraph_blocks(
+    lines: usize,
+    height: &str,
+    line_box: Option<&str>,
+    last_line_width: &str,
+    border_radius: &str,
+) -> Vec<String> {
+    let heading = format!(
+        "display: block; background-color: var(--skeleton-rs-base); width: 100%; height: calc({height} * 1.75); border-radius: {border_radius}; margin-block-end: 0.25em;"
+    );
+    std::iter::once(heading)
+        .chain(text_line_blocks(
+            lines,
+            height,
+            line_box,
+            last_line_width,
+            border_radius,
+        ))
+        .collect()
+}
+
+/// Returns the widths of the lines of a `SkeletonText` placeholder.
+///
+/// Each line but the last is between 85% and 100% wide; the last of several lines is
+/// between 40% and 70% wide, mimicking the end of a paragraph. The widths come from a small
+/// PRNG seeded with `seed`, so the same arguments always produce the same widths and server
+/// and client renders match during hydration.
+///
+/// ```rust
+/// use skeleton_rs::common::text_line_widths;
+///
+/// let widths = text_line_widths(4, 7);
+/// assert_eq!(widths.len(), 4);
+/// assert_eq!(widths, text_line_widths(4, 7));
+/// for width in &widths[..3] {
+///     let percent: u32 = width.trim_end_matches('%').parse().unwrap();
+///     assert!((85..=100).contains(&percent));
+/// }
+/// let last: u32 = widths[3].trim_end_matches('%').parse().unwrap();
+/// assert!((40..=70).contains(&last));
+/// ```
+pub fn text_line_widths(lines: usize, seed: u64) -> Vec<String> {
+    let mut state = seed;
+    (0..lines)
+        .map(|line| {
+            let (min, span) = if lines > 1 && line + 1 == lines {
+                (40, 31)
+            } else {
+                (85, 16)
+            };
+            format!("{}%", min + splitmix64(&mut state) % span)
+        })
+        .collect()
+}
+
+// SplitMix64 is tiny, fast, and gives well-spread values even for small consecutive seeds.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Default duration of a single wave sweep.
+pub const DEFAULT_WAVE_DURATION: &str = "1.6s";
+
+/// Transition applied when `morph` is enabled, smoothing shape and size changes.
+pub const MORPH_TRANSITION: &str =
+    "transition: border-radius 0.3s ease, width 0.3s ease, height 0.3s ease;";
+
+/// Keyframes of the transform-based wave, one per sweep direction.
+pub const TRANSFORM_WAVE_KEYFRAMES: &str = "@keyframes skeleton-rs-wave-tx-ltr {
+    0% { transform: translateX(-100%); }
+    100% { transform: translateX(100%); }
+}
+
+@keyframes skeleton-rs-wave-tx-rtl {
+    0% { transform: translateX(100%); }
+    100% { transform: translateX(-100%); }
+}
+
+@keyframes skeleton-rs-wave-tx-ttb {
+    0% { transform: translateY(-100%); }
+    100% { transform: translateY(100%); }
+}
+
+@keyframes skeleton-rs-wave-tx-btt {
+    0% { transform: translateY(100%); }
+    100% { transform: translateY(-100%); }
+}";
+
+/// Inset shadow of `inset_shadow`, a darker shade of the skeleton's base color.
+pub const INSET_SHADOW_STYLE: &str = " box-shadow: inset 0 1px 3px \
+     color-mix(in srgb, var(--skeleton-rs-base), #000 25%);";
+
+/// Overlay drawn over revealed children while `revalidating`.
+///
+/// Its child carries the skeleton's own animation class; the reduced opacity turns the full
+/// animation into a subtle shimmer that doesn't obscure the content.
+pub const REVALIDATING_OVERLAY_STYLE: &str =
+    "position: absolute; inset: 0; opacity: 0.2; pointer-events: none; overflow: hidden;";
+
+/// Fill of the `revalidating` overlay, animated like the skeleton itself.
+pub const REVALIDATING_FILL_STYLE: &str =
+    "display: block; width: 100%; height: 100%; background-color: var(--skeleton-rs-base);";
+
+/// Stylesheet of the fade-in applied to content revealed by a staggered `SkeletonGroup`.
+pub const REVEAL_STYLE: &str = "@keyframes skeleton-rs-reveal {
+    from { opacity: 0; }
+    to { opacity: 1; }
+}
+
+.skeleton-reveal {
+    animation: skeleton-rs-reveal 0.3s ease-out both;
+}";
+
+/// Staggers the reveal of the skeletons inside a `SkeletonGroup`.
+///
+/// Shared with the group's children through context. Each child revealing its content
+/// reserves the next slot, so items streaming in fade in at least `stagger_ms` apart.
+#[derive(Clone, Debug, Default)]
+pub struct RevealScheduler {
+    stagger_ms: Rc<Cell<u32>>,
+    next_slot: Rc<Cell<f64>>,
+}
+
+impl PartialEq for RevealScheduler {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.next_slot, &other.next_slot) && self.stagger_ms() == other.stagger_ms()
+    }
+}
+
+impl RevealScheduler {
+    pub fn new(stagger_ms: u32) -> Self {
+        RevealScheduler {
+            stagger_ms: Rc::new(Cell::new(stagger_ms)),
+            next_slot: Rc::default(),
+        }
+    }
+
+    /// Returns the scheduler of a named timeline.
+    ///
+    /// Every group using the same `timeline` shares one schedule, so staggered reveals
+    /// continue across groups instead of restarting in each of them.
+    pub fn shared(timeline: &'static str, stagger_ms: u32) -> Self {
+        thread_local! {
+            static TIMELINES: RefCell<HashMap<&'static str, RevealScheduler>> =
+                RefCell::default();
+        }
+        let scheduler =
+            TIMELINES.with(|timelines| timelines.borrow_mut().entry(timeline).or_default().clone());
+        scheduler.set_stagger_ms(stagger_ms);
+        scheduler
+    }
+
+    /// Returns the stagger between reveals, in milliseconds. `0` disables staggering.
+    pub fn stagger_ms(&self) -> u32 {
+        self.stagger_ms.get()
+    }
+
+    pub fn set_stagger_ms(&self, stagger_ms: u32) {
+        self.stagger_ms.set(stagger_ms);
+    }
+
+    /// Reserves the next reveal slot and returns how long to wait for it, in milliseconds.
+    pub fn next_delay(&self, now_ms: f64) -> f64 {
+        let start = self.next_slot.get().max(now_ms);
+        self.next_slot.set(start + f64::from(self.stagger_ms()));
+        start - now_ms
+    }
+}
+
+/// Stylesheet of the shimmering top bar shown over content while `refreshing` is enabled.
+pub const REFRESHING_STYLE: &str = "@keyframes skeleton-rs-refresh {
+    0% { background-position: 200% 0; }
+    100% { background-position: -200% 0; }
+}
+
+.skeleton-refreshing {
+    position: relative;
+}
+
+.skeleton-refreshing::before {
+    content: \"\";
+    position: absolute;
+    top: 0;
+    left: 0;
+    right: 0;
+    height: 2px;
+    background: linear-gradient(90deg, transparent, var(--skeleton-rs-base), transparent);
+    background-size: 200% 100%;
+    animation: skeleton-rs-refresh 1.2s linear infinite;
+    pointer-events: none;
+    z-index: 1;
+}";
+
+/// Duration of a single pulse cycle, in milliseconds.
+pub const PULSE_DURATION_MS: f64 = 1500.0;
+
+/// Parses a CSS `<time>` such as `"1.5s"` or `"200ms"` into milliseconds.
+///
+/// Returns `None` for values that are not a finite, non-negative time.
+///
+/// # Examples
+///
+/// ```rust
+/// use skeleton_rs::common::css_time_ms;
+///
+/// assert_eq!(css_time_ms("1.5s"), Some(1500.0));
+/// assert_eq!(css_time_ms(" 200ms "), Some(200.0));
+/// assert_eq!(css_time_ms("fast"), None);
+/// assert_eq!(css_time_ms("-1s"), None);
+/// ```
+pub fn css_time_ms(value: &str) -> Option<f64> {
+    let value = value.trim();
+    let (number, scale) = match value.strip_suffix("ms") {
+        Some(number) => (number, 1.0),
+        None => (value.strip_suffix('s')?, 1000.0),
+    };
+    number
+        .parse::<f64>()
+        .ok()
+        .filter(|n| n.is_finite() && *n >= 0.0)
+        .map(|n| n * scale)
+}
+
+/// Factor by which `low_power` lengthens an animation cycle.
+pub const LOW_POWER_SLOWDOWN: f64 = 2.0;
+
+/// Builds the declarations that throttle an animation in low-power mode.
+///
+/// The cycle of `duration_ms` is lengthened by `LOW_POWER_SLOWDOWN` and played in 8 discrete
+/// steps instead of a continuous curve, so the browser repaints 8 times per cycle rather than
+/// on every frame.
+pub fn low_power_style(duration_ms: f64) -> String {
+    format!(
+        " animation-duration: {:.0}ms; animation-timing-function: steps(8, jump-none);",
+        duration_ms * LOW_POWER_SLOWDOWN
+    )
+}
+
+/// Resolves the duration of a single wave sweep in milliseconds.
+///
+/// Follows the same rules as `wave_duration`.
+pub fn wave_duration_ms(sweeps_per_second: Option<f32>) -> f64 {
+    match sweeps_per_second {
+        Some(rate) if rate.is_finite() && rate > 0.0 => 1000.0 / f64::from(rate),
+        _ => 1600.0,
+    }
+}
+
+/// Builds the negative `animation-delay` aligning an animation to the global timeline.
+///
+/// `now_ms` is the time elapsed since the page's time origin. Offsetting every skeleton by
+/// its position within the current cycle makes them animate in phase, regardless of when
+/// they were mounted.
+pub fn global_sync_delay(duration_ms: f64, now_ms: f64) -> String {
+    if duration_ms <= 0.0 {
+        return String::new();
+    }
+    format!(" animation-delay: -{:.0}ms;", now_ms % duration_ms)
+}
+
+/// Returns how long a skeleton waits before hiding once `show` becomes `true`, in
+/// milliseconds.
+///
+/// `shown_for_ms` is how long the skeleton has been on screen, or `None` when it is not shown,
+/// e.g. because `delay_ms` has not elapsed yet; such a skeleton hides at once. A shown one waits
+/// for the longer of `hide_debounce_ms` and the rest of its `min_display_ms`, so it never
+/// flashes for less than `min_display_ms`.
+///
+/// ```rust
+/// use skeleton_rs::common::hide_wait_ms;
+///
+/// // The data arrived within `delay_ms`: the skeleton never appeared and nothing is kept.
+/// assert_eq!(hide_wait_ms(None, 500, 0), 0);
+/// // The delay elapsed and the skeleton appeared 50ms ago: it stays for the other 450ms.
+/// assert_eq!(hide_wait_ms(Some(50.0), 500, 0), 450);
+/// // It has been shown long enough to hide right away.
+/// assert_eq!(hide_wait_ms(Some(800.0), 500, 0), 0);
+/// // A longer `hide_debounce_ms` wins over the remaining display time.
+/// assert_eq!(hide_wait_ms(Some(450.0), 500, 200), 200);
+/// ```
+pub fn hide_wait_ms(shown_for_ms: Option<f64>, min_display_ms: u32, hide_debounce_ms: u32) -> u32 {
+    let Some(shown_for_ms) = shown_for_ms else {
+        return 0;
+    };
+    let remaining = (f64::from(min_display_ms) - shown_for_ms).max(0.0).ceil() as u32;
+    remaining.max(hide_debounce_ms)
+}
+
+/// Returns how long a skeleton waits before applying a change of `show`, in milliseconds,
+/// and whether the skeleton is visible once it does.
+///
+/// Hiding waits for `hide_wait_ms`; showing waits for `delay_ms`. Each flip of `show`
+/// replaces the change still pending, so the skeleton only appears once `show` stays
+/// `false` for the whole delay, and rapid toggles don't make it flicker.
+///
+/// ```rust
+/// use skeleton_rs::common::{initially_visible, visibility_change};
+///
+/// // `show` flips at these times, in milliseconds of a fake clock, as on a flaky network.
+/// let flips = [(0, false), (150, true), (300, false), (700, true), (1050, false), (1100, true)];
+/// // The skeleton mounts loading, hidden until `delay_ms` elapses.
+/// let (mut visible, mut shown_at) = (initially_visible(false, 200), 0);
+/// let mut pending = None;
+/// let mut changes = Vec::new();
+/// for now in 0..2000 {
+///     if let Some(&(_, show)) = flips.iter().find(|(at, _)| *at == now) {
+///         let shown_for_ms = visible.then(|| f64::from(now - shown_at));
+///         let (wait_ms, target) = visibility_change(show, shown_for_ms, 200, 500, 100);
+///         pending = Some((now + wait_ms, target));
+///     }
+///     if let Some((_, target)) = pending.filter(|(at, _)| *at == now) {
+///         pending = None;
+///         if target != visible {
+///             (visible, shown_at) = (target, now);
+///             changes.push((now, target));
+///         }
+///     }
+/// }
+/// // Only the load that outlasted `delay_ms` showed the skeleton, for `min_display_ms`.
+/// assert_eq!(changes, [(500, true), (1000, false)]);
+/// ```
+pub fn visibility_change(
+    show: bool,
+    shown_for_ms: Option<f64>,
+    delay_ms: u32,
+    min_display_ms: u32,
+    hide_debounce_ms: u32,
+) -> (u32, bool) {
+    if show {
+        (
+            hide_wait_ms(shown_for_ms, min_display_ms, hide_debounce_ms),
+            false,
+        )
+    } else {
+        (delay_ms, true)
+    }
+}
+
+/// Returns whether an uncontrolled skeleton is visible on its first render.
+///
+/// A skeleton mounted loading with a `delay_ms` starts hidden; `visibility_change` then
+/// schedules it to appear once the delay elapses, so fast loads never flash it.
+///
+/// ```rust
+/// use skeleton_rs::common::{initially_visible, visibility_change};
+///
+/// assert!(initially_visible(false, 0));
+/// assert!(!initially_visible(true, 0));
+///
+/// // Mounted loading with a 300ms delay: hidden, with the show scheduled after the delay.
+/// assert!(!initially_visible(false, 300));
+/// assert_eq!(visibility_change(false, None, 300, 500, 0), (300, true));
+/// // The data arrives first: the pending show is replaced by an immediate hide, so the
+/// // skeleton never appears and `min_display_ms` is not applied.
+/// assert_eq!(visibility_change(true, None, 300, 500, 0), (0, false));
+/// ```
+pub fn initially_visible(show: bool, delay_ms: u32) -> bool {
+    !show && delay_ms == 0
+}
+
+/// Returns the first-render visibility the components use.
+///
+/// Server-side rendering runs no timers to end the delay, so a loading skeleton is rendered
+/// right away there instead of leaving the page blank.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn mount_visible(show: bool, delay_ms: u32) -> bool {
+    if !cfg!(target_arch = "wasm32") {
+        return !show;
+    }
+    initially_visible(show, delay_ms)
+}
+
+/// Resolves the `animation-duration` of a wave sweep.
+///
+/// A positive `sweeps_per_second` rate is converted into the duration of a single sweep
+/// (e.g. `2.0` becomes `"0.5s"`). Missing, zero, negative, or non-finite rates fall back to
+/// `DEFAULT_WAVE_DURATION`.
+///
+/// ```rust
+/// use skeleton_rs::common::{DEFAULT_WAVE_DURATION, wave_duration};
+///
+/// assert_eq!(wave_duration(None), DEFAULT_WAVE_DURATION);
+/// assert_eq!(wave_duration(Some(2.0)), "0.5s");
+/// assert_eq!(wave_duration(Some(0.5)), "2s");
+/// assert_eq!(wave_duration(Some(0.0)), DEFAULT_WAVE_DURATION);
+/// assert_eq!(wave_duration(Some(-1.0)), DEFAULT_WAVE_DURATION);
+/// assert_eq!(wave_duration(Some(f32::NAN)), DEFAULT_WAVE_DURATION);
+/// ```
+pub fn wave_duration(sweeps_per_second: Option<f32>) -> String {
+    match sweeps_per_second {
+        Some(rate) if rate.is_finite() && rate > 0.0 => format!("{}s", 1.0 / rate),
+        _ => DEFAULT_WAVE_DURATION.to_string(),
+    }
+}
+
+/// Builds the inline CSS that caps a text skeleton at `max_lines` lines.
+///
+/// Mirrors `-webkit-line-clamp`: the height is limited to `max_lines` line boxes (`lh`) and
+/// the final line is masked down to 60% of the width, mimicking an ellipsis-truncated bar.
+/// Combined with a size measured from the children (`infer_size`, `measure_once`), the
+/// measured height is capped as well, since `max-height` wins over `height`.
+///
+/// The `lh` unit needs a recent browser (Chrome 109, Firefox 120, Safari 16.4); older ones
+/// ignore the cap and the mask. `build_skeleton_style` skips a `max_lines` of `0`.
+///
+/// ```rust
+/// use skeleton_rs::common::{StyleParams, build_skeleton_style, line_clamp_style};
+///
+/// assert!(line_clamp_style(3).starts_with(" max-height: 3lh;"));
+///
+/// let measured = build_skeleton_style(&StyleParams {
+///     max_lines: Some(3),
+///     measured_size: Some((320.0, 96.0)),
+///     ..StyleParams::default()
+/// });
+/// assert!(measured.contains(" max-height: 3lh;"));
+/// assert!(measured.contains(" height: 96px;"));
+///
+/// let unclamped = build_skeleton_style(&StyleParams {
+///     max_lines: Some(0),
+///     ..StyleParams::default()
+/// });
+/// assert!(!unclamped.contains("lh"));
+/// ```
+pub fn line_clamp_style(max_lines: usize) -> String {
+    let mask = "linear-gradient(#000 0 0) top / 100% calc(100% - 1lh) no-repeat, \
+                linear-gradient(#000 0 0) bottom left / 60% 1lh no-repeat";
+    format!(" max-height: {max_lines}lh; -webkit-mask: {mask}; mask: {mask};")
+}
+
+/// Returns the number of bars of a multi-line text skeleton with `lines` lines, capped by
+/// `max_lines`.
+///
+/// A `max_lines` of `0` is ignored like `None`, rather than rendering an empty skeleton.
+///
+/// ```rust
+/// use skeleton_rs::common::clamped_lines;
+///
+/// assert_eq!(clamped_lines(5, None), 5);
+/// assert_eq!(clamped_lines(5, Some(3)), 3);
+/// assert_eq!(clamped_lines(2, Some(3)), 2);
+/// assert_eq!(clamped_lines(5, Some(0)), 5);
+/// ```
+pub fn clamped_lines(lines: usize, max_lines: Option<usize>) -> usize {
+    max_lines
+        .filter(|max| *max > 0)
+        .map_or(lines, |max| lines.min(max))
+}
+
+/// Returns the corner radius a skeleton of the given variant is drawn with.
+///
+/// Shaped variants (circles, buttons, icons, ...) use a fixed radius; the remaining
+/// variants use the caller-supplied `border_radius`.
+///
+/// Each variant with the default `"4px"` and with an explicit `"12px"`:
+///
+/// ```rust
+/// use skeleton_rs::Variant;
+/// use skeleton_rs::common::radius_for;
+///
+/// let table = [
+///     (Variant::Text, "4px", "12px"),
+///     (Variant::Circular, "50%", "50%"),
+///     (Variant::Rectangular, "0", "0"),
+///     (Variant::Rounded, "8px", "8px"),
+///     (Variant::Image, "4px", "12px"),
+///     (Variant::Avatar, "50%", "50%"),
+///     (Variant::Button, "6px", "6px"),
+///     (Variant::Icon, "4px", "4px"),
+///     (Variant::Checkerboard, "4px", "12px"),
+///     (Variant::Divider, "0", "0"),
+///     (Variant::Quote, "4px", "12px"),
+///     (Variant::Card, "4px", "12px"),
+///     (Variant::Table, "4px", "12px"),
+///     (Variant::Paragraph, "4px", "12px"),
+/// ];
+/// for (variant, default, explicit) in table {
+///     assert_eq!(radius_for(variant, "4px"), default, "{}", variant.as_str());
+///     assert_eq!(radius_for(variant, "12px"), explicit, "{}", variant.as_str());
+/// }
+/// ```
+pub fn radius_for(variant: Variant, border_radius: &str) -> &str {
+    match variant {
+        Variant::Circular | Variant::Avatar => "50%",
+        Variant::Rectangular | Variant::Divider => "0",
+        Variant::Rounded => "8px",
+        Variant::Button => "6px",
+        Variant::Icon => "4px",
+        Variant::Text
+        | Variant::Image
+        | Variant::Checkerboard
+        | Variant::Quote
+        | Variant::Card
+        | Variant::Table
+        | Variant::Paragraph => border_radius,
+    }
+}
+
+/// Returns `true` if a skeleton is purely decorative and should be hidden from assistive
+/// technology.
+///
+/// A skeleton conveys loading status, and must stay exposed, once it has a
+/// `loading_label`, is marked `aria_busy`, or uses `role="status"`.
+///
+/// ```rust
+/// use skeleton_rs::common::is_decorative;
+///
+/// assert!(is_decorative(None, false, None));
+/// assert!(is_decorative(None, false, Some("presentation")));
+/// assert!(!is_decorative(Some("Loading orders"), false, None));
+/// assert!(!is_decorative(None, true, None));
+/// assert!(!is_decorative(None, false, Some("status")));
+/// ```
+pub fn is_decorative(loading_label: Option<&str>, aria_busy: bool, role: Option<&str>) -> bool {
+    loading_label.is_none() && !aria_busy && role != Some("status")
+}
+
+/// Returns the `aria-live` politeness of a skeleton with the given resolved `role`.
+///
+/// A `status` skeleton is a polite live region, so screen readers announce its label
+/// without interrupting the user; other roles are not live regions.
+///
+/// ```rust
+/// use skeleton_rs::common::aria_live;
+///
+/// assert_eq!(aria_live(Some("status")), Some("polite"));
+/// assert_eq!(aria_live(Some("presentation")), None);
+/// ```
+pub fn aria_live(role: Option<&str>) -> Option<&'static str> {
+    (role == Some("status")).then_some("polite")
+}
+
+/// Builds the margin declaration for a CSS `margin` shorthand value.
+///
+/// With `logical` set, the physical sides are mapped to logical properties
+/// (`margin-block` / `margin-inline`), so left and right margins flip in right-to-left
+/// layouts. Values containing functions such as `calc()` are kept as a plain `margin`.
+pub fn margin_style(margin: &str, logical: bool) -> String {
+    let sides: Vec<&str> = margin.split_whitespace().collect();
+    if !logical || margin.contains('(') {
+        return format!("margin: {margin};");
+    }
+    let (top, right, bottom, left) = match sides[..] {
+        [all] => (all, all, all, all),
+        [block, inline] => (block, inline, block, inline),
+        [top, inline, bottom] => (top, inline, bottom, inline),
+        [top, right, bottom, left] => (top, right, bottom, left),
+        _ => return format!("margin: {margin};"),
+    };
+    format!("margin-block: {top} {bottom}; margin-inline: {left} {right};")
+}
+
+/// Timing of the default pulse animation.
+pub const DEFAULT_PULSE_TIMING: &str = "1.5s ease-in-out";
+
+/// Class of the default pulse animation, defined in the base stylesheet.
+pub const PULSE_CLASS: &str = "skeleton-rs--pulse";
+
+/// Class of the default left-to-right wave animation, defined in the base stylesheet.
+pub const WAVE_CLASS: &str = "skeleton-rs--wave";
+
+/// Builds the declaration block of a pulse animation running the `keyframes` rule.
+pub fn pulse_declaration(keyframes: &str, timing: &str) -> String {
+    format!("animation: {keyframes} {timing} infinite;")
+}
+
+/// Builds the declaration block of a wave animation sweeping in `direction`.
+///
+/// The gradient only reads the `--skeleton-rs-base` and `--skeleton-rs-highlight`
+/// variables, so switching the theme at runtime restyles a waving skeleton through its
+/// inline style, while its animation class stays the same.
+///
+/// ```rust
+/// use skeleton_rs::common::{StyleParams, animation_class, build_skeleton_style, wave_declaration};
+/// use skeleton_rs::{Direction, Theme};
+///
+/// let wave = wave_declaration(&Direction::LeftToRight, "1.6s linear");
+/// assert!(wave.contains("var(--skeleton-rs-base)"));
+/// assert!(!wave.contains("#e0e0e0") && !wave.contains("#444444"));
+///
+/// let style = |theme: Theme| {
+///     let base = theme.base_color();
+///     let params = StyleParams {
+///         background_color: &base,
+///         highlight_color: theme.highlight(),
+///         ..StyleParams::default()
+///     };
+///     (build_skeleton_style(&params), animation_class(&wave))
+/// };
+/// let (light, light_class) = style(Theme::Light);
+/// let (dark, dark_class) = style(Theme::CustomPair { base: "#444444", highlight: "#666666" });
+/// assert!(light.contains("--skeleton-rs-base: #e0e0e0;"));
+/// assert!(dark.contains("--skeleton-rs-base: #444444;"));
+/// assert!(dark.contains("--skeleton-rs-highlight: #666666;"));
+/// assert_eq!(light_class, dark_class);
+/// ```
+pub fn wave_declaration(direction: &Direction, timing: &str) -> String {
+    format!(
+        "background: {}; background-size: {}; animation: {} {timing} infinite;",
+        shimmer_gradient(direction.gradient_angle(), "var(--skeleton-rs-base)"),
+        direction.wave_background_size(),
+        direction.wave_keyframes_name()
+    )
+}
+
+/// Builds the declaration block of a shimmer animation moving its band in `direction`.
+///
+/// ```rust
+/// use skeleton_rs::Direction;
+/// use skeleton_rs::common::{shimmer_declaration, wave_declaration};
+///
+/// let shimmer = shimmer_declaration(&Direction::TopToBottom, "1.6s linear");
+/// assert!(shimmer.contains("linear-gradient(180deg"));
+/// assert!(shimmer.contains("animation: skeleton-rs-shimmer 1.6s linear infinite;"));
+/// assert_ne!(shimmer, wave_declaration(&Direction::TopToBottom, "1.6s linear"));
+/// ```
+pub fn shimmer_declaration(direction: &Direction, timing: &str) -> String {
+    format!(
+        "background: {}; background-size: 300% 300%; animation: {} {timing} infinite;",
+        shimmer_band_gradient(direction.gradient_angle(), "var(--skeleton-rs-base)"),
+        direction.shimmer_keyframes_name()
+    )
+}
+
+/// Builds the declaration block of a glow animation.
+///
+/// ```rust
+/// use skeleton_rs::common::glow_declaration;
+///
+/// assert_eq!(
+///     glow_declaration("1.5s ease-in-out"),
+///     "animation: skeleton-rs-glow 1.5s ease-in-out infinite;"
+/// );
+/// ```
+pub fn glow_declaration(timing: &str) -> String {
+    format!("animation: skeleton-rs-glow {timing} infinite;")
+}
+
+/// Builds the declaration block of a pulse animation over the shimmer gradient.
+pub fn pulse_gradient_declaration(angle: i64, timing: &str) -> String {
+    format!(
+        "background: {}; animation: skeleton-rs-pulse {timing} infinite;",
+        shimmer_gradient(angle, "var(--skeleton-rs-base)")
+    )
+}
+
+/// Returns a deterministic class name for an animation declaration block.
+///
+/// The default pulse and wave map to the static `PULSE_CLASS` and `WAVE_CLASS` rules of the
+/// base stylesheet. Other skeletons sharing identical animation parameters map to the same
+/// generated class, so the declaration is injected into the document once instead of being
+/// inlined on every element.
+///
+/// ```rust
+/// use skeleton_rs::common::{
+///     DEFAULT_PULSE_TIMING, PULSE_CLASS, animation_class, pulse_declaration,
+/// };
+///
+/// let default_pulse = pulse_declaration("skeleton-rs-pulse", DEFAULT_PULSE_TIMING);
+/// assert_eq!(animation_class(&default_pulse), PULSE_CLASS);
+///
+/// let slow_pulse = pulse_declaration("skeleton-rs-pulse", "3s ease-in-out");
+/// assert!(animation_class(&slow_pulse).starts_with("skeleton-rs-anim-"));
+///
+/// // Two skeletons with the same parameters share one class, and so one injected rule.
+/// let twin = pulse_declaration("skeleton-rs-pulse", "3s ease-in-out");
+/// assert_eq!(animation_class(&twin), animation_class(&slow_pulse));
+/// let fast_pulse = pulse_declaration("skeleton-rs-pulse", "800ms ease-in-out");
+/// assert_ne!(animation_class(&fast_pulse), animation_class(&slow_pulse));
+/// ```
+pub fn animation_class(declaration: &str) -> String {
+    if declaration == default_pulse_declaration() {
+        PULSE_CLASS.to_string()
+    } else if declaration == default_wave_declaration() {
+        WAVE_CLASS.to_string()
+    } else {
+        format!("skeleton-rs-anim-{:08x}", fnv1a(declaration))
+    }
+}
+
+/// Returns whether `class` is one of the animation classes of the base stylesheet, which
+/// need no stylesheet of their own.
+pub fn is_preset_animation_class(class: &str) -> bool {
+    class == PULSE_CLASS || class == WAVE_CLASS
+}
+
+fn default_pulse_declaration() -> String {
+    pulse_declaration(PulseMode::Opacity.keyframes_name(), DEFAULT_PULSE_TIMING)
+}
+
+fn default_wave_declaration() -> String {
+    wave_declaration(
+        &Direction::LeftToRight,
+        &format!("{DEFAULT_WAVE_DURATION} linear"),
+    )
+}
+
+// FNV-1a keeps generated names stable across builds and platforms.
+fn fnv1a(input: &str) -> u32 {
+    input.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// Skeleton attached to an existing element through a generated class.
+///
+/// Instead of rendering a wrapper, the skeleton is drawn by a `::before` pseudo-element
+/// overlaying the element the class is applied to.
+#[derive(Clone, PartialEq)]
+pub struct AttachedSkeleton {
+    /// The theme of the overlay.
+    pub theme: Theme,
+    /// Animation of the overlay.
+    pub animation: Animation,
+    /// Border radius of the overlay.
+    pub border_radius: &'static str,
+}
+
+impl Default for AttachedSkeleton {
+    fn default() -> Self {
+        AttachedSkeleton {
+            theme: Theme::default(),
+            animation: Animation::default(),
+            border_radius: "4px",
+        }
+    }
+}
+
+impl AttachedSkeleton {
+    /// Returns the generated class name and the stylesheet implementing it.
+    ///
+    /// Identical options always produce the same class, so the stylesheet is injected once.
+    /// Animations run with the same timings as the components, `DEFAULT_PULSE_TIMING` and
+    /// `DEFAULT_WAVE_DURATION`.
+    ///
+    /// ```rust
+    /// use skeleton_rs::common::{DEFAULT_PULSE_TIMING, DEFAULT_WAVE_DURATION};
+    /// use skeleton_rs::{Animation, AttachedSkeleton};
+    ///
+    /// let (_, pulse) = AttachedSkeleton::default().stylesheet();
+    /// assert!(pulse.contains(&format!("skeleton-rs-pulse {DEFAULT_PULSE_TIMING} infinite")));
+    /// let wave = AttachedSkeleton {
+    ///     animation: Animation::Wave,
+    ///     ..AttachedSkeleton::default()
+    /// };
+    /// assert!(wave.stylesheet().1.contains(&format!(
+    ///     "skeleton-rs-attached-wave {DEFAULT_WAVE_DURATION} linear infinite"
+    /// )));
+    /// ```
+    pub fn stylesheet(&self) -> (String, String) {
+        let base = self.theme.base_color();
+        let gradient = format!(
+            "background-image: {}; background-size: 200% 100%;",
+            shimmer_gradient(90, &base)
+        );
+        let (keyframes, animation) = match self.animation {
+            Animation::Pulse => (
+                PULSE_KEYFRAMES,
+                format!("animation: skeleton-rs-pulse {DEFAULT_PULSE_TIMING} infinite;"),
+            ),
+            Animation::Wave => (
+                ATTACHED_WAVE_KEYFRAMES,
+                format!(
+                    "{gradient} animation: skeleton-rs-attached-wave {DEFAULT_WAVE_DURATION} linear infinite;"
+                ),
+            ),
+            Animation::PulseGradient => (
+                PULSE_KEYFRAMES,
+                format!("{gradient} animation: skeleton-rs-pulse {DEFAULT_PULSE_TIMING} infinite;"),
+            ),
+            Animation::Shimmer => (
+                SHIMMER_KEYFRAMES,
+                format!(
+                    "background-image: {}; background-size: 300% 300%; animation: skeleton-rs-shimmer {DEFAULT_WAVE_DURATION} linear infinite;",
+                    shimmer_band_gradient(90, &base)
+                ),
+            ),
+            Animation::Glow => (
+                GLOW_KEYFRAMES,
+                format!(
+                    "--skeleton-rs-base: {base}; animation: skeleton-rs-glow {DEFAULT_PULSE_TIMING} infinite;"
+                ),
+            ),
+            Animation::None => ("", String::new()),
+        };
+        let declarations = format!(
+            "content: \"\"; position: absolute; inset: 0; z-index: 1; pointer-events: none; border-radius: {}; background-color: {base}; {animation}",
+            self.border_radius
+        );
+        let class = format!("skeleton-rs-attached-{:08x}", fnv1a(&declarations));
+        let css = format!(
+            "{keyframes} .{class} {{ position: relative; }} .{class}::before {{ {declarations} }}"
+        );
+        (class, css)
+    }
+}
+
+const ATTACHED_WAVE_KEYFRAMES: &str = "@keyframes skeleton-rs-attached-wave {
+    0% { background-position: 200% 0; }
+    100% { background-position: -200% 0; }
+}";
+
+/// App-wide skeleton defaults, supplied to a subtree by `SkeletonProvider`.
+///
+/// A skeleton resolves its theme, animation, and direction with the precedence explicit
+/// prop > provider > enum default: fields left `None` here fall through to the default.
+///
+/// # Examples
+///
+/// ```rust
+/// use skeleton_rs::{Animation, SkeletonDefaults, Theme};
+///
+/// let defaults = SkeletonDefaults {
+///     theme: Some(Theme::Dark),
+///     ..SkeletonDefaults::default()
+/// };
+/// // An explicit prop wins, then the provider, then the enum default.
+/// assert!(defaults.theme(Some(&Theme::Light)) == Theme::Light);
+/// assert!(defaults.theme(None) == Theme::Dark);
+/// assert!(defaults.animation(None) == Animation::Pulse);
+/// ```
+#[derive(Clone, PartialEq, Default)]
+pub struct SkeletonDefaults {
+    /// Theme of skeletons without a `theme` prop.
+    pub theme: Option<Theme>,
+    /// Animation of skeletons without an `animation` prop.
+    pub animation: Option<Animation>,
+    /// Direction of skeletons without a `direction` prop.
+    pub direction: Option<Direction>,
+}
+
+impl SkeletonDefaults {
+    /// Returns these defaults with unset fields taken from the enclosing `outer` defaults, so
+    /// nested providers only override what they set.
+    pub fn inherit(self, outer: &SkeletonDefaults) -> Self {
+        SkeletonDefaults {
+            theme: self.theme.or_else(|| outer.theme.clone()),
+            animation: self.animation.or_else(|| outer.animation.clone()),
+            direction: self.direction.or_else(|| outer.direction.clone()),
+        }
+    }
+
+    /// Resolves the theme of a skeleton whose `theme` prop is `prop`.
+    pub fn theme(&self, prop: Option<&Theme>) -> Theme {
+        prop.or(self.theme.as_ref()).cloned().unwrap_or_default()
+    }
+
+    /// Resolves the animation of a skeleton whose `animation` prop is `prop`.
+    pub fn animation(&self, prop: Option<&Animation>) -> Animation {
+        prop.or(self.animation.as_ref())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Resolves the direction of a skeleton whose `direction` prop is `prop`.
+    pub fn direction(&self, prop: Option<&Direction>) -> Direction {
+        prop.or(self.direction.as_ref())
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+/// Framework-independent description of a skeleton, built in plain Rust code.
+///
+/// Useful for data-driven layouts, e.g. skeletons generated from a deserialized layout
+/// description, where writing `html!`/`rsx!`/`view!` for every shape is impractical. Each
+/// backend converts it into its `SkeletonProps` with `From`, leaving the remaining props at
+/// their defaults.
+///
+/// # Examples
+///
+/// ```rust
+/// use skeleton_rs::{Animation, SkeletonConfig, Variant};
+///
+/// let config = SkeletonConfig::new()
+///     .variant(Variant::Text)
+///     .width("50%")
+///     .animation(Animation::Wave)
+///     .lines(3);
+/// assert_eq!(config.width, "50%");
+/// assert_eq!(config.height, "1em");
+/// assert_eq!(config.lines, 3);
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct SkeletonConfig {
+    /// The visual variant of the skeleton.
+    pub variant: Variant,
+    /// Animation style applied to the skeleton; `None` inherits the `SkeletonDefaults`.
+    pub animation: Option<Animation>,
+    /// Direction of the wave animation; `None` inherits the `SkeletonDefaults`.
+    pub direction: Option<Direction>,
+    /// The theme of the skeleton appearance; `None` inherits the `SkeletonDefaults`.
+    pub theme: Option<Theme>,
+    /// The width of the skeleton.
+    pub width: String,
+    /// The height of the skeleton.
+    pub height: String,
+    /// The border radius of the skeleton.
+    pub border_radius: &'static str,
+    /// Number of text lines drawn by a `Variant::Text` skeleton.
+    pub lines: usize,
+    /// Delay, in milliseconds, before the skeleton is shown.
+    pub delay_ms: u32,
+    /// Whether the skeleton is shown once it scrolls into view.
+    pub animate_on_visible: bool,
+    /// Additional inline styles appended to the skeleton.
+    pub custom_style: String,
+}
+
+impl Default for SkeletonConfig {
+    fn default() -> Self {
+        SkeletonConfig {
+            variant: Variant::default(),
+            animation: None,
+            direction: None,
+            theme: None,
+            width: "100%".to_string(),
+            height: "1em".to_string(),
+            border_radius: "4px",
+            lines: 1,
+            delay_ms: 0,
+            animate_on_visible: false,
+            custom_style: String::new(),
+        }
+    }
+}
+
+impl SkeletonConfig {
+    /// Returns a configuration holding the default value of every prop.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the visual variant.
+    pub fn variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Sets the animation style.
+    pub fn animation(mut self, animation: Animation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
+    /// Sets the direction of the wave animation.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the theme.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Sets the width, as a CSS length.
+    pub fn width(mut self, width: impl Into<String>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height, as a CSS length.
+    pub fn height(mut self, height: impl Into<String>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the border radius.
+    pub fn border_radius(mut self, border_radius: &'static str) -> Self {
+        self.border_radius = border_radius;
+        self
+    }
+
+    /// Sets the number of text lines.
+    pub fn lines(mut self, lines: usize) -> Self {
+        self.lines = lines;
+        self
+    }
+
+    /// Sets the delay, in milliseconds, before the skeleton is shown.
+    pub fn delay_ms(mut self, delay_ms: u32) -> Self {
+        self.delay_ms = delay_ms;
+        self
+    }
+
+    /// Sets whether the skeleton is shown once it scrolls into view.
+    pub fn animate_on_visible(mut self, animate_on_visible: bool) -> Self {
+        self.animate_on_visible = animate_on_visible;
+        self
+    }
+
+    /// Sets additional inline styles.
+    pub fn custom_style(mut self, custom_style: impl Into<String>) -> Self {
+        self.custom_style = custom_style.into();
+        self
+    }
+}
+
+/// A single stop of a custom keyframe animation.
+///
+/// Unset fields are left out of the stop, so the browser interpolates them from the
+/// neighbouring stops.
+#[derive(Clone, PartialEq, Default)]
+pub struct KeyframeStep {
+    /// Opacity at this stop, clamped to `0.0..=1.0` by `clamp_opacity`.
+    pub opacity: Option<f32>,
+    /// Background at this stop, e.g. a color or gradient.
+    pub background: Option<&'static str>,
+}
+
+/// Compiles `(percent, step)` pairs into a uniquely named `@keyframes` rule.
+///
+/// Returns the animation name and its CSS. Stops are sorted by percentage and percentages
+/// above `100` are clamped. Identical stop lists always compile to the same name, so the
+/// rule is injected into the document once.
+///
+/// ```rust
+/// use skeleton_rs::KeyframeStep;
+/// use skeleton_rs::common::{DEFAULT_PULSE_MIN_OPACITY, compile_keyframes};
+///
+/// let step = |opacity| KeyframeStep {
+///     opacity: Some(opacity),
+///     background: None,
+/// };
+/// let (_, css) = compile_keyframes(&[(0, step(1.0)), (50, step(f32::NAN)), (100, step(2.0))]);
+/// assert!(css.contains(&format!("50% {{ opacity: {DEFAULT_PULSE_MIN_OPACITY}; }}")));
+/// assert!(css.contains("100% { opacity: 1; }"));
+/// assert!(!css.contains("NaN"));
+/// ```
+pub fn compile_keyframes(steps: &[(u8, KeyframeStep)]) -> (String, String) {
+    let mut steps: Vec<_> = steps.iter().collect();
+    steps.sort_by_key(|(percent, _)| *percent);
+    let body: String = steps
+        .iter()
+        .map(|(percent, step)| {
+            let mut declarations = String::new();
+            if let Some(opacity) = step.opacity {
+                declarations.push_str(&format!(" opacity: {};", clamp_opacity(opacity)));
+            }
+            if let Some(background) = step.background {
+                declarations.push_str(&format!(" background: {background};"));
+            }
+            format!(" {}% {{{declarations} }}", (*percent).min(100))
+        })
+        .collect();
+    let name = format!("skeleton-rs-kf-{:08x}", fnv1a(&body));
+    let css = format!("@keyframes {name} {{{body} }}");
+    (name, css)
+}
+
+/// Builds an instance-scoped copy of an animation rule.
+///
+/// Every `@keyframes` name declared in `keyframes` is suffixed with `scope`, both in its
+/// declaration and in the `animation` / `animation-name` values of `declaration`, so the
+/// returned `(class, css)` neither depends on nor clashes with the global stylesheet.
+/// Only whole identifiers are renamed, so variables sharing a name's prefix, such as
+/// `--skeleton-rs-pulse-min`, keep working.
+///
+/// ```rust
+/// use skeleton_rs::common::{
+///     PULSE_KEYFRAMES, StyleParams, build_skeleton_style, pulse_declaration,
+///     scoped_animation_css,
+/// };
+///
+/// let declaration = pulse_declaration("skeleton-rs-pulse", "1.5s ease-in-out");
+/// let (class, css) = scoped_animation_css(7, PULSE_KEYFRAMES, &declaration);
+/// assert_eq!(class, "skeleton-rs-scoped-7");
+/// assert!(css.contains("@keyframes skeleton-rs-pulse-7 {"));
+/// assert!(css.contains(".skeleton-rs-scoped-7 { animation: skeleton-rs-pulse-7 1.5s"));
+///
+/// // The keyframes still read the minimum the element sets.
+/// let style = build_skeleton_style(&StyleParams {
+///     pulse_min_opacity: 0.2,
+///     ..StyleParams::default()
+/// });
+/// assert!(style.contains("--skeleton-rs-pulse-min: 0.2;"));
+/// assert!(css.contains("var(--skeleton-rs-pulse-min, 0.4)"));
+/// assert!(!css.contains("skeleton-rs-pulse-7-min"));
+/// ```
+pub fn scoped_animation_css(scope: usize, keyframes: &str, declaration: &str) -> (String, String) {
+    let class = format!("skeleton-rs-scoped-{scope}");
+    let mut parts = keyframes.split("@keyframes ");
+    let mut scoped_keyframes = parts.next().unwrap_or_default().to_string();
+    let mut names = Vec::new();
+    for part in parts {
+        let end = part.find(|c: char| !is_ident_char(c)).unwrap_or(part.len());
+        names.push(&part[..end]);
+        scoped_keyframes.push_str(&format!(
+            "@keyframes {}-{scope}{}",
+            &part[..end],
+            &part[end..]
+        ));
+    }
+    let scoped_declaration = declaration
+        .split(';')
+        .map(|item| match item.split_once(':') {
+            Some((property, value))
+                if matches!(property.trim(), "animation" | "animation-name") =>
+            {
+                let value = map_identifiers(value, |name| {
+                    if names.contains(&name) {
+                        format!("{name}-{scope}")
+                    } else {
+                        name.to_string()
+                    }
+                });
+                format!("{property}:{value}")
+            }
+            _ => item.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(";");
+    let css = format!("{scoped_keyframes} .{class} {{ {scoped_declaration} }}");
+    (class, css)
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+/// Replaces every whole CSS identifier of `css` with `rename(identifier)`.
+fn map_identifiers(css: &str, rename: impl Fn(&str) -> String) -> String {
+    let mut mapped = String::with_capacity(css.len());
+    let mut start = None;
+    for (index, c) in css.char_indices() {
+        match (is_ident_char(c), start) {
+            (true, None) => start = Some(index),
+            (true, Some(_)) => {}
+            (false, Some(from)) => {
+                mapped.push_str(&rename(&css[from..index]));
+                mapped.push(c);
+                start = None;
+            }
+            (false, None) => mapped.push(c),
+        }
+    }
+    if let Some(from) = start {
+        mapped.push_str(&rename(&css[from..]));
+    }
+    mapped
+}
+
+/// Returns a process-wide unique number, used to scope per-instance styles and DOM ids.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn next_unique_id() -> usize {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// DOM id of a skeleton element.
+///
+/// Skeletons without an explicit `id` get a generated `skeleton-rs-{n}`, so effects looking
+/// the element up by id find their own skeleton rather than the first one on the page.
+#[cfg(feature = "dio")]
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum SkeletonId {
+    Given(&'static str),
+    Generated(usize),
+}
+
+#[cfg(feature = "dio")]
+impl fmt::Display for SkeletonId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkeletonId::Given(id) => f.write_str(id),
+            SkeletonId::Generated(n) => write!(f, "skeleton-rs-{n}"),
+        }
+    }
+}
+
+/// Rule emitted into shadow roots so a custom element hosting skeletons lays out as a block.
+///
+/// Page styles targeting the host element take precedence over it.
+pub const SHADOW_HOST_STYLE: &str = " :host { display: block; }";
+
+/// Media query matching an OS preference for a dark color scheme.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+const DARK_SCHEME_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// Returns whether the OS currently prefers a dark color scheme.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn prefers_dark_scheme() -> bool {
+    // Server-side rendering has no window to query; it renders the light scheme.
+    if !cfg!(target_arch = "wasm32") {
+        return false;
+    }
+    web_sys::window()
+        .and_then(|w| w.match_media(DARK_SCHEME_QUERY).ok().flatten())
+        .is_some_and(|query| query.matches())
+}
+
+/// Listener of color scheme preference changes, removed when dropped.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) struct ColorSchemeListener {
+    query: web_sys::MediaQueryList,
+    callback: web_sys::wasm_bindgen::closure::Closure<dyn FnMut(web_sys::Event)>,
+}
+
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+impl Drop for ColorSchemeListener {
+    fn drop(&mut self) {
+        use web_sys::wasm_bindgen::JsCast;
+
+        let _ = self
+            .query
+            .remove_event_listener_with_callback("change", self.callback.as_ref().unchecked_ref());
+    }
+}
+
+/// Calls `on_change` with the new preference whenever the OS color scheme changes.
+///
+/// Shared by all backends so `Theme::System` behaves identically everywhere.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn watch_color_scheme(
+    mut on_change: impl FnMut(bool) + 'static,
+) -> Option<ColorSchemeListener> {
+    use web_sys::wasm_bindgen::JsCast;
+    use web_sys::wasm_bindgen::closure::Closure;
+
+    if !cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    let query = web_sys::window()?.match_media(DARK_SCHEME_QUERY).ok()??;
+    let watched = query.clone();
+    let callback =
+        Closure::wrap(
+            Box::new(move |_event: web_sys::Event| on_change(watched.matches()))
+                as Box<dyn FnMut(web_sys::Event)>,
+        );
+    query
+        .add_event_listener_with_callback("change", callback.as_ref().unchecked_ref())
+        .ok()?;
+    Some(ColorSchemeListener { query, callback })
+}
+
+/// Node receiving injected stylesheets.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+enum StyleRoot {
+    Head(web_sys::Document),
+    Shadow(web_sys::Document, web_sys::ShadowRoot),
+}
+
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+impl StyleRoot {
+    /// Resolves the document head, or the open shadow root of the element with id
+    /// `shadow_host`.
+    fn resolve(shadow_host: Option<&str>) -> Option<Self> {
+        // Server-side rendering has no document to inject into.
+        if !cfg!(target_arch = "wasm32") {
+            return None;
+        }
+        let doc = web_sys::window().and_then(|w| w.document())?;
+        match shadow_host {
+            Some(host) => {
+                let root = doc.get_element_by_id(host)?.shadow_root()?;
+                Some(StyleRoot::Shadow(doc, root))
+            }
+            None => Some(StyleRoot::Head(doc)),
+        }
+    }
+
+    fn contains(&self, id: &str) -> bool {
+        match self {
+            StyleRoot::Head(doc) => doc.get_element_by_id(id).is_some(),
+            StyleRoot::Shadow(_, root) => root.get_element_by_id(id).is_some(),
+        }
+    }
+
+    fn append(&self, id: &str, css: &str) {
+        let (StyleRoot::Head(doc) | StyleRoot::Shadow(doc, _)) = self;
+        let Ok(style_elem) = doc.create_element("style") else {
+            return;
+        };
+        style_elem.set_id(id);
+        apply_style_nonce(&style_elem);
+        style_elem.set_inner_html(css);
+        match self {
+            StyleRoot::Head(doc) => {
+                if let Some(head) = doc.head() {
+                    let _ = head.append_child(&style_elem);
+                }
+            }
+            StyleRoot::Shadow(_, root) => {
+                let _ = root.append_child(&style_elem);
+            }
+        }
+    }
+}
+
+thread_local! {
+    static STYLE_NONCE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Sets the CSP nonce carried by every `<style>` element the skeletons create from now on,
+/// as supplied by `SkeletonProvider`.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn set_style_nonce(nonce: &str) {
+    STYLE_NONCE.with_borrow_mut(|current| {
+        if current.as_deref() != Some(nonce) {
+            *current = Some(nonce.to_string());
+        }
+    });
+}
+
+/// Sets the `nonce` attribute of a `<style>` element about to be appended, so pages with a
+/// `style-src 'nonce-...'` Content-Security-Policy accept it.
+fn apply_style_nonce(style_elem: &web_sys::Element) {
+    STYLE_NONCE.with_borrow(|nonce| {
+        if let Some(nonce) = nonce {
+            let _ = style_elem.set_attribute("nonce", nonce);
+        }
+    });
+}
+
+/// Appends a `<style>` element with the given `id` and `css` to the document head.
+///
+/// Does nothing if an element with that `id` already exists, so it is safe to call on
+/// every render.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn inject_style(id: &str, css: &str) {
+    inject_style_in(None, id, css);
+}
+
+/// Like `inject_style`, but targets the open shadow root of the element with id
+/// `shadow_host` when one is given.
+///
+/// Stylesheets in the document head do not apply inside shadow trees, so skeletons rendered
+/// in a custom element need their rules injected into its shadow root instead. Does nothing
+/// with the `no-runtime-style` feature.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn inject_style_in(shadow_host: Option<&str>, id: &str, css: &str) {
+    if !runtime_styles_enabled() {
+        return;
+    }
+    if let Some(root) = StyleRoot::resolve(shadow_host) {
+        if !root.contains(id) {
+            root.append(id, css);
+        }
+    }
+}
+
+/// Injects the rule of a generated animation class, together with its custom `keyframes`.
+///
+/// The `<style>` is keyed by the class, so skeletons sharing animation parameters share a
+/// single rule. Backends call this while rendering rather than from an effect, so the
+/// element is animated from its first frame. Preset classes are part of the base stylesheet
+/// and are skipped.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn inject_animation_class(
+    shadow_host: Option<&str>,
+    anim_class: &str,
+    keyframes: &str,
+    declaration: &str,
+) {
+    if !is_preset_animation_class(anim_class) {
+        inject_style_in(
+            shadow_host,
+            anim_class,
+            &format!("{keyframes} .{anim_class} {{ {declaration} }}"),
+        );
+    }
+}
+
+/// Injects the stylesheet shared by every skeleton, unless it is already present or inlined
+/// by the server, see `uses_static_styles`.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn inject_base_stylesheet(shadow_host: Option<&str>) {
+    if !uses_static_styles(shadow_host) && !style_injected(shadow_host, "skeleton-rs-style") {
+        let mut css = base_stylesheet();
+        if shadow_host.is_some() {
+            css.push_str(SHADOW_HOST_STYLE);
+        }
+        inject_style_in(shadow_host, "skeleton-rs-style", &css);
+    }
+}
+
+/// Returns whether a stylesheet with the given `id` was already injected into the target
+/// of `inject_style_in`.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn style_injected(shadow_host: Option<&str>, id: &str) -> bool {
+    StyleRoot::resolve(shadow_host).is_none_or(|root| root.contains(id))
+}
+
+/// Size, in `em`, substituted for zero-sized dimensions when the fallback is enabled.
+pub const MIN_VISIBLE_EM: f32 = 1.0;
+
+/// Converts a size computed in `em` into a CSS length.
+///
+/// Without a `root_font_size` the value is emitted as `em`, relative to the element's own
+/// font. With one (in pixels), it is resolved to `px` up front so computed sizes stay
+/// accurate in apps that change the root font size. Non-positive or non-finite sizes are
+/// ignored.
+pub fn em_length(em: f32, root_font_size: Option<f32>) -> String {
+    match root_font_size {
+        Some(size) if size.is_finite() && size > 0.0 => format!("{}px", em * size),
+        _ => format!("{em}em"),
+    }
+}
+
+/// Returns `true` if a CSS length is empty or resolves to zero (e.g. `"0"`, `"0px"`, `"0.0%"`).
+pub fn is_zero_length(value: &str) -> bool {
+    let value = value.trim();
+    if value.is_empty() {
+        return true;
+    }
+    let number = value.trim_end_matches(|c: char| c.is_ascii_alphabetic() || c == '%');
+    number.parse::<f64>().is_ok_and(|n| n == 0.0)
+}
+
+/// Validates the resolved `width` and `height` of a skeleton.
+///
+/// A zero or empty dimension renders an invisible skeleton, so debug builds emit a
+/// `tracing` warning for it. When a `fallback` size is given, such dimensions are replaced
+/// with it; otherwise they are returned unchanged.
+pub fn checked_dimensions<'a>(
+    width: &'a str,
+    height: &'a str,
+    fallback: Option<&'a str>,
+) -> (&'a str, &'a str) {
+    let check = |name: &str, value: &'a str| {
+        if !is_zero_length(value) {
+            return value;
+        }
+        if cfg!(debug_assertions) {
+            tracing::warn!(
+                "skeleton-rs: `{name}` resolves to zero ({value:?}), the skeleton will be invisible"
+            );
+        }
+        fallback.unwrap_or(value)
+    };
+    (check("width", width), check("height", height))
+}
+
+/// Builds the `width`/`height` declarations of a skeleton.
+///
+/// With an `aspect_ratio` and a `height` left at its default (`1em`) or `auto`, the height
+/// is omitted so the browser derives it from the width, letting fluid skeletons reserve
+/// their box from the container width alone. An explicit height is kept alongside the ratio.
+pub fn size_style(width: &str, height: &str, aspect_ratio: Option<&str>) -> String {
+    match aspect_ratio {
+        Some(ratio) if matches!(height, "1em" | "auto") => {
+            format!("width: {width}; aspect-ratio: {ratio};")
+        }
+        Some(ratio) => format!("width: {width}; height: {height}; aspect-ratio: {ratio};"),
+        None => format!("width: {width}; height: {height};"),
+    }
+}
+
+/// Resolved inputs of a skeleton's inline style and class list.
+///
+/// Both framework backends fill this from their props and render state, then call
+/// `build_skeleton_style` and `build_class_names`, so identical props produce identical
+/// markup. The `Default` value matches the default props of `Skeleton`.
+#[derive(Clone, PartialEq)]
+pub struct StyleParams<'a> {
+    pub variant: Variant,
+    /// Resolved base color, e.g. from `Theme::scheme_color` or the auto-contrast color.
+    pub background_color: &'a str,
+    /// Explicit highlight color, see `Theme::highlight`.
+    pub highlight_color: Option<&'a str>,
+    /// Lowest opacity of the pulse animation, clamped by `clamp_opacity`.
+    pub pulse_min_opacity: f32,
+    /// Radius after applying variant overrides, see `radius_for`.
+    pub border_radius: &'a str,
+    pub border_radius_top: Option<&'a str>,
+    pub border_radius_bottom: Option<&'a str>,
+    pub width: &'a str,
+    pub height: &'a str,
+    pub aspect_ratio: Option<&'a str>,
+    pub infer_size: bool,
+    pub zero_size_fallback: bool,
+    /// Scales the skeleton with its container, see `responsive_size_style`.
+    pub responsive: bool,
+    pub root_font_size: Option<f32>,
+    pub icon_size: Option<&'a str>,
+    pub thickness: &'a str,
+    pub display: &'a str,
+    pub position: &'a str,
+    pub overflow: &'a str,
+    pub margin: &'a str,
+    pub logical_props: bool,
+    pub line_height: &'a str,
+    pub font_size: Option<&'a str>,
+    pub max_width: Option<&'a str>,
+    pub min_width: Option<&'a str>,
+    pub max_height: Option<&'a str>,
+    pub min_height: Option<&'a str>,
+    pub grid_span: Option<usize>,
+    /// Whether the text variant renders more than one line.
+    pub multi_line: bool,
+    pub line_gap: &'a str,
+    pub max_lines: Option<usize>,
+    /// Container style of a parsed layout template, see `template_layout`.
+    pub template_layout: Option<&'a str>,
+    pub tag: &'a str,
+    pub outline_only: bool,
+    pub inset_shadow: bool,
+    /// Negative delay aligning the animation with the global clock, see `global_sync_delay`.
+    pub sync_delay: Option<&'a str>,
+    /// Size in pixels measured from the wrapped children.
+    pub measured_size: Option<(f64, f64)>,
+    pub wave_offset: Option<&'a str>,
+    pub hover_filter: Option<&'a HoverFilter>,
+    pub focus_outline: Option<&'a FocusOutline>,
+    pub morph: bool,
+    pub custom_style: &'a str,
+    /// Fade-out duration in milliseconds while the skeleton is fading out.
+    pub fading_ms: Option<u32>,
+    pub animate_on_hover: bool,
+    pub pause_on_hover: bool,
+    pub respect_reduced_motion: bool,
+    pub animate_on_focus: bool,
+    pub animate_on_active: bool,
+    pub hide_on_print: bool,
+    pub container_responsive: bool,
+    pub ignore_group_defaults: bool,
+}
+
+impl Default for StyleParams<'_> {
+    fn default() -> Self {
+        Self {
+            variant: Variant::default(),
+            background_color: "#e0e0e0",
+            highlight_color: None,
+            pulse_min_opacity: DEFAULT_PULSE_MIN_OPACITY,
+            border_radius: "4px",
+            border_radius_top: None,
+            border_radius_bottom: None,
+            width: "100%",
+            height: "1em",
+            aspect_ratio: None,
+            infer_size: false,
+            zero_size_fallback: false,
+            responsive: false,
+            root_font_size: None,
+            icon_size: None,
+            thickness: "1px",
+            display: "inline-block",
+            position: "relative",
+            overflow: "hidden",
+            margin: "",
+            logical_props: false,
+            line_height: "1",
+            font_size: None,
+            max_width: None,
+            min_width: None,
+            max_height: None,
+            min_height: None,
+            grid_span: None,
+            multi_line: false,
+            line_gap: "0.5em",
+            max_lines: None,
+            template_layout: None,
+            tag: "div",
+            outline_only: false,
+            inset_shadow: false,
+            sync_delay: None,
+            measured_size: None,
+            wave_offset: None,
+            hover_filter: None,
+            focus_outline: None,
+            morph: false,
+            custom_style: "",
+            fading_ms: None,
+            animate_on_hover: false,
+            pause_on_hover: false,
+            respect_reduced_motion: true,
+            animate_on_focus: false,
+            animate_on_active: false,
+            hide_on_print: false,
+            container_responsive: false,
+            ignore_group_defaults: false,
+        }
+    }
+}
+
+/// Builds the inline style of a skeleton, excluding its animation.
+///
+/// # Examples
+///
+/// ```rust
+/// use skeleton_rs::common::{StyleParams, build_skeleton_style};
+///
+/// let style = build_skeleton_style(&StyleParams {
+///     width: "200px",
+///     max_width: Some("50%"),
+///     ..StyleParams::default()
+/// });
+/// assert!(style.starts_with("width: 200px; height: 1em;"));
+/// assert!(style.contains("--skeleton-rs-base: #e0e0e0;"));
+/// assert!(style.ends_with(" max-width: 50%;"));
+///
+/// let responsive = build_skeleton_style(&StyleParams {
+///     width: "300px",
+///     height: "150px",
+///     responsive: true,
+///     ..StyleParams::default()
+/// });
+/// assert!(responsive.starts_with("width: 100%; max-width: 300px; height: auto;"));
+/// ```
+///
+/// An invalid `hover_filter` is dropped, with a warning in debug builds, rather than
+/// emitting a declaration the browser rejects.
+///
+/// ```rust
+/// use skeleton_rs::common::{HoverFilter, StyleParams, build_skeleton_style};
+///
+/// let hover = |filter| {
+///     build_skeleton_style(&StyleParams {
+///         hover_filter: Some(&filter),
+///         ..StyleParams::default()
+///     })
+/// };
+/// assert!(hover(HoverFilter::Brightness(1.2)).contains("--skeleton-hover-filter: brightness(1.2);"));
+/// assert!(!hover(HoverFilter::Brightness(f32::NAN)).contains("--skeleton-hover-filter"));
+/// assert!(!hover(HoverFilter::Contrast(-1.0)).contains("--skeleton-hover-filter"));
+/// ```
+pub fn build_skeleton_style(params: &StyleParams) -> String {
+    let background_color = params.background_color;
+    let effective_radius = params.border_radius;
+    let margin = margin_style(params.margin, params.logical_props);
+    let multi_line = params.multi_line || params.variant == Variant::Paragraph;
+    let mut style = String::new();
+
+    if params.infer_size {
+        style.push_str(&format!(
+            "--skeleton-rs-base: {background_color}; background-color: var(--skeleton-rs-base); border-radius: {effective_radius}; display: {}; position: {}; overflow: {}; {}",
+            params.display, params.position, params.overflow, margin
+        ));
+    } else {
+        let default_icon_size = em_length(1.0, params.root_font_size);
+        let (width, height) = match params.variant {
+            _ if params.template_layout.is_some() => (params.width, "auto"),
+            Variant::Icon => {
+                let size = params.icon_size.unwrap_or(&default_icon_size);
+                (size, size)
+            }
+            Variant::Divider => ("100%", params.thickness),
+            Variant::Quote => (params.width, "auto"),
+            Variant::Card if matches!(params.height, "1em" | "auto") => (params.width, "auto"),
+            Variant::Text | Variant::Paragraph if multi_line => (params.width, "auto"),
+            _ => (params.width, params.height),
+        };
+        let min_size = em_length(MIN_VISIBLE_EM, params.root_font_size);
+        let (width, height) = checked_dimensions(
+            width,
+            height,
+            params.zero_size_fallback.then_some(min_size.as_str()),
+        );
+        let size = params
+            .responsive
+            .then(|| responsive_size_style(width, height, params.aspect_ratio))
+            .flatten()
+            .unwrap_or_else(|| size_style(width, height, params.aspect_ratio));
+        style.push_str(&format!(
+            "{size} --skeleton-rs-base: {background_color}; background-color: var(--skeleton-rs-base); border-radius: {effective_radius}; display: {}; position: {}; overflow: {}; {} line-height: {};",
+            params.display,
+            params.position,
+            params.overflow,
+            margin,
+            params.line_height
+        ));
+    }
+
+    let pulse_min_opacity = clamp_opacity(params.pulse_min_opacity);
+    if pulse_min_opacity != DEFAULT_PULSE_MIN_OPACITY {
+        style.push_str(&format!(" --skeleton-rs-pulse-min: {pulse_min_opacity};"));
+    }
+    if let Some(highlight) = params.highlight_color {
+        style.push_str(&format!(" --skeleton-rs-highlight: {highlight};"));
+    }
+    if let Some(size) = params.font_size {
+        style.push_str(&format!(" font-size: {size};"));
+    }
+    if let Some(top) = params.border_radius_top {
+        style.push_str(&format!(
+            " border-top-left-radius: {top}; border-top-right-radius: {top};"
+        ));
+    }
+    if let Some(bottom) = params.border_radius_bottom {
+        style.push_str(&format!(
+            " border-bottom-left-radius: {bottom}; border-bottom-right-radius: {bottom};"
+        ));
+    }
+
+    if let Some(max_w) = params.max_width {
+        style.push_str(&format!(" max-width: {max_w};"));
+    }
+    if let Some(min_w) = params.min_width {
+        style.push_str(&format!(" min-width: {min_w};"));
+    }
+    if let Some(max_h) = params.max_height {
+        style.push_str(&format!(" max-height: {max_h};"));
+    }
+    if let Some(min_h) = params.min_height {
+        style.push_str(&format!(" min-height: {min_h};"));
+    }
+    if let Some(span) = params.grid_span {
+        style.push_str(&format!(" grid-column: span {span};"));
+    }
+    if params.variant == Variant::Quote {
+        style.push_str(QUOTE_LAYOUT);
+    }
+    if params.variant == Variant::Card {
+        style.push_str(CARD_LAYOUT);
+    }
+    if multi_line {
+        style.push_str(&text_lines_layout(params.line_gap));
+    }
+    if let Some(layout) = params.template_layout {
+        style.push_str(layout);
+    }
+    if params.tag == "button" {
+        style.push_str(" border: none; padding: 0; font: inherit;");
+    }
+    if params.variant == Variant::Checkerboard {
+        style.push_str(CHECKERBOARD_STYLE);
+    }
+    if params.outline_only {
+        style.push_str(
+            " background: transparent; border: 2px solid var(--skeleton-rs-base); box-sizing: border-box;",
+        );
+    }
+    if params.inset_shadow {
+        style.push_str(INSET_SHADOW_STYLE);
+    }
+    if let Some(max_lines) = params.max_lines.filter(|max| *max > 0 && !multi_line) {
+        style.push_str(&line_clamp_style(max_lines));
+    }
+    if let Some(delay) = params.sync_delay {
+        style.push_str(delay);
+    }
+    if let Some((width, height)) = params.measured_size {
+        style.push_str(&format!(" width: {width}px; height: {height}px;"));
+    }
+    if let Some(offset) = params.wave_offset {
+        style.push_str(&format!(" --skeleton-wave-offset: {offset};"));
+    }
+    if let Some(filter) = params.hover_filter {
+        match filter.validated() {
+            Ok(filter) => style.push_str(&format!(" --skeleton-hover-filter: {filter};")),
+            Err(error) if cfg!(debug_assertions) => {
+                tracing::warn!("skeleton-rs: dropping `hover_filter`, {error}");
+            }
+            Err(_) => {}
+        }
+    }
+    if let Some(outline) = params.focus_outline {
+        style.push_str(&format!(" --skeleton-focus-outline: {outline};"));
+    }
+    if params.morph {
+        style.push(' ');
+        style.push_str(MORPH_TRANSITION);
+    }
+
+    style.push_str(params.custom_style);
+    if let Some(fade_out_ms) = params.fading_ms {
+        style.push_str(&format!(
+            " animation: none; opacity: 0; transition: opacity {fade_out_ms}ms ease-out;"
+        ));
+    }
+    style
+}
+
+/// Builds the class list of a skeleton.
+///
+/// `anim_class` is the class carrying the animation, if the skeleton element itself
+/// animates (composite placeholders animate their blocks instead).
+///
+/// # Examples
+///
+/// ```rust
+/// use skeleton_rs::common::{StyleParams, build_class_names};
+///
+/// let params = StyleParams {
+///     animate_on_hover: true,
+///     ..StyleParams::default()
+/// };
+/// assert_eq!(
+///     build_class_names(&params, Some("skeleton-anim-1")),
+///     "skeleton-rs skeleton-anim-1 skeleton-hover skeleton-reduced-motion"
+/// );
+/// ```
+pub fn build_class_names(params: &StyleParams, anim_class: Option<&str>) -> String {
+    let mut class_names = String::from("skeleton-rs");
+    if let Some(anim_class) = anim_class {
+        class_names.push(' ');
+        class_names.push_str(anim_class);
+    }
+    let flags = [
+        (params.animate_on_hover, " skeleton-hover"),
+        (params.pause_on_hover, " skeleton-pause-on-hover"),
+        (params.respect_reduced_motion, " skeleton-reduced-motion"),
+        (params.animate_on_focus, " skeleton-focus"),
+        (params.animate_on_active, " skeleton-active"),
+        (params.hide_on_print, " skeleton-print-hidden"),
+        (
+            params.container_responsive,
+            " skeleton-container-responsive",
+        ),
+        (params.ignore_group_defaults, " skeleton-group-ignore"),
+    ];
+    for (enabled, class) in flags {
+        if enabled {
+            class_names.push_str(class);
+        }
+    }
+    class_names
+}
+
+/// Builds the size declarations of a `responsive` skeleton.
+///
+/// The skeleton fills its container up to the given `width`, and its height follows from
+/// an aspect ratio: `aspect_ratio` when set, otherwise the ratio of `width` to `height`
+/// when both are lengths in the same absolute unit. Returns `None` when no ratio can be
+/// derived (e.g. percentage sizes), so the caller keeps the fixed size.
+///
+/// # Examples
+///
+/// ```rust
+/// use skeleton_rs::common::responsive_size_style;
+///
+/// assert_eq!(
+///     responsive_size_style("300px", "150px", None).as_deref(),
+///     Some("width: 100%; max-width: 300px; height: auto; aspect-ratio: 300 / 150;")
+/// );
+/// assert_eq!(
+///     responsive_size_style("100%", "8em", Some("16 / 9")).as_deref(),
+///     Some("width: 100%; max-width: 100%; height: auto; aspect-ratio: 16 / 9;")
+/// );
+/// assert_eq!(responsive_size_style("100%", "1em", None), None);
+/// ```
+pub fn responsive_size_style(
+    width: &str,
+    height: &str,
+    aspect_ratio: Option<&str>,
+) -> Option<String> {
+    fn split(value: &str) -> Option<(&str, &str)> {
+        let value = value.trim();
+        let unit_start = value
+            .find(|c: char| c.is_ascii_alphabetic() || c == '%')
+            .unwrap_or(value.len());
+        let (number, unit) = value.split_at(unit_start);
+        number
+            .parse::<f64>()
+            .ok()
+            .filter(|n| n.is_finite() && *n > 0.0)
+            .map(|_| (number, unit))
+    }
+    let ratio = match aspect_ratio {
+        Some(ratio) => ratio.to_string(),
+        None => match (split(width)?, split(height)?) {
+            ((w, w_unit), (h, h_unit)) if w_unit == h_unit && w_unit != "%" => {
+                format!("{w} / {h}")
+            }
+            _ => return None,
+        },
+    };
+    Some(format!(
+        "width: 100%; max-width: {width}; height: auto; aspect-ratio: {ratio};"
+    ))
+}
+
+/// Parses a computed `rgb()`/`rgba()` color into its channels and alpha.
+///
+/// Accepts both the legacy comma-separated syntax and the space-separated syntax with an
+/// optional `/ alpha`, as returned by `getComputedStyle`.
+pub fn parse_rgb(value: &str) -> Option<(u8, u8, u8, f32)> {
+    let inner = value
+        .trim()
+        .strip_prefix("rgba(")
+        .or_else(|| value.trim().strip_prefix("rgb("))?
+        .strip_suffix(')')?;
+    let parts: Vec<&str> = inner
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .collect();
+    let channel = |part: &str| part.parse::<f32>().ok().map(|c| c.clamp(0.0, 255.0) as u8);
+    let (r, g, b) = (
+        channel(parts.first()?)?,
+        channel(parts.get(1)?)?,
+        channel(parts.get(2)?)?,
+    );
+    let alpha = match parts.get(3) {
+        Some(alpha) => alpha.parse().ok()?,
+        None => 1.0,
+    };
+    Some((r, g, b, alpha))
+}
+
+/// Picks a skeleton base color that stands out against the given background.
+///
+/// Light backgrounds get a translucent dark tint and dark backgrounds a translucent light
+/// one, so the skeleton stays visible whatever the exact shade.
+pub fn contrasting_base_color(r: u8, g: u8, b: u8) -> &'static str {
+    let luminance = 0.2126 * f32::from(r) + 0.7152 * f32::from(g) + 0.0722 * f32::from(b);
+    if luminance > 127.5 {
+        "rgba(0, 0, 0, 0.11)"
+    } else {
+        "rgba(255, 255, 255, 0.13)"
+    }
+}
+
+/// Returns a base color contrasting with the background behind `element`.
+///
+/// Walks up from the element's parent until it finds an ancestor with a non-transparent
+/// computed `background-color`. Returns `None` if every ancestor is transparent.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn auto_contrast_color(element: &web_sys::Element) -> Option<&'static str> {
+    let window = web_sys::window()?;
+    let mut current = element.parent_element();
+    while let Some(ancestor) = current {
+        let background = window
+            .get_computed_style(&ancestor)
+            .ok()
+            .flatten()
+            .and_then(|style| style.get_property_value("background-color").ok());
+        if let Some((r, g, b, alpha)) = background.as_deref().and_then(parse_rgb) {
+            if alpha > 0.0 {
+                return Some(contrasting_base_color(r, g, b));
+            }
+        }
+        current = ancestor.parent_element();
+    }
+    None
+}
+
+/// Box of an element mirrored by `SkeletonMirror`, in pixels.
+///
+/// Offsets are relative to the top-left corner of the mirrored root element.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MirrorBox {
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Measures `root` and the boxes of its leaf elements.
+///
+/// Returns the size of the root followed by one box per element without element children
+/// (text blocks, images, buttons, ...). Zero-sized elements are skipped, and a root without
+/// element children is mirrored as a single box.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn measure_mirror(root: &web_sys::Element) -> (MirrorBox, Vec<MirrorBox>) {
+    let origin = root.get_bounding_client_rect();
+    let root_box = MirrorBox {
+        left: 0.0,
+        top: 0.0,
+        width: origin.width(),
+        height: origin.height(),
+    };
+    let mut boxes = Vec::new();
+    collect_leaf_boxes(root, &origin, &mut boxes);
+    if root.first_element_child().is_none() {
+        boxes.push(root_box);
+    }
+    (root_box, boxes)
+}
+
+#[cfg(any(feature = "yew", feature = "dio"))]
+fn collect_leaf_boxes(
+    element: &web_sys::Element,
+    origin: &web_sys::DomRect,
+    boxes: &mut Vec<MirrorBox>,
+) {
+    let mut child = element.first_element_child();
+    while let Some(current) = child {
+        if current.first_element_child().is_some() {
+            collect_leaf_boxes(&current, origin, boxes);
+        } else {
+            let rect = current.get_bounding_client_rect();
+            if rect.width() > 0.0 && rect.height() > 0.0 {
+                boxes.push(MirrorBox {
+                    left: rect.left() - origin.left(),
+                    top: rect.top() - origin.top(),
+                    width: rect.width(),
+                    height: rect.height(),
+                });
+            }
+        }
+        child = current.next_element_sibling();
+    }
+}
+
+/// Builds the inline style of a single mirrored skeleton block.
+pub fn mirror_box_style(
+    mirror_box: &MirrorBox,
+    base_color: &str,
+    border_radius: &str,
+    animate: bool,
+) -> String {
+    let MirrorBox {
+        left,
+        top,
+        width,
+        height,
+    } = mirror_box;
+    let animation = if animate {
+        format!(" animation: skeleton-rs-pulse {DEFAULT_PULSE_TIMING} infinite;")
+    } else {
+        String::new()
+    };
+    format!(
+        "position: absolute; left: {left}px; top: {top}px; width: {width}px; height: {height}px; --skeleton-rs-base: {base_color}; background-color: var(--skeleton-rs-base); border-radius: {border_radius};{animation}"
+    )
+}
+
+/// Emits a `tracing` event for a skeleton lifecycle step.
+///
+/// Only expands to anything with the `trace` feature enabled, so lifecycle logging is
+/// zero-cost by default. Events use the `skeleton_rs::lifecycle` target and carry the
+/// instance number and DOM id of the skeleton.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+macro_rules! trace_lifecycle {
+    ($instance:expr, $id:expr, $($fields:tt)+) => {{
+        #[cfg(feature = "trace")]
+        tracing::debug!(
+            target: "skeleton_rs::lifecycle",
+            instance = $instance,
+            id = ?$id,
+            $($fields)+
+        );
+        #[cfg(not(feature = "trace"))]
+        let _ = (&$instance, &$id);
+    }};
+}
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) use trace_lifecycle;
+
+/// Returns a unique number identifying a skeleton instance in lifecycle events.
+///
+/// Always `0` without the `trace` feature.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn next_instance_id() -> usize {
+    #[cfg(feature = "trace")]
+    {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static NEXT_INSTANCE: AtomicUsize = AtomicUsize::new(0);
+        NEXT_INSTANCE.fetch_add(1, Ordering::Relaxed)
+    }
+
+    #[cfg(not(feature = "trace"))]
+    {
+        0
+    }
+}
+
+/// Returns the milliseconds elapsed since the page's time origin.
+///
+/// Falls back to `0.0` when no browser `Performance` API is available (SSR, native tests).
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn now_ms() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|w| w.performance())
+            .map_or(0.0, |p| p.now())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        0.0
+    }
+}
+
+/// Callback scheduled with `run_after`, cancelled when dropped.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+#[must_use = "dropping the timer cancels its callback"]
+pub struct Timer {
+    #[cfg(target_arch = "wasm32")]
+    timeout: gloo_timers::callback::Timeout,
+}
+
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+impl Timer {
+    /// Cancels the callback if it hasn't run yet.
+    pub fn cancel(self) {
+        #[cfg(target_arch = "wasm32")]
+        drop(self.timeout.cancel());
+    }
+
+    /// Lets the callback run even though the timer is no longer held.
+    pub fn detach(self) {
+        #[cfg(target_arch = "wasm32")]
+        self.timeout.forget();
+    }
+}
+
+/// Runs `callback` once `delay_ms` milliseconds have elapsed, unless the returned timer is
+/// dropped first.
+///
+/// On `wasm32` targets the callback is scheduled with `gloo_timers`. Other targets (SSR,
+/// native tests) have no browser timer available, so the callback runs immediately and the
+/// skeleton behaves as if the delay had already elapsed.
+///
+/// ```rust
+/// use skeleton_rs::common::run_after;
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// let fired = Rc::new(Cell::new(false));
+/// let _timer = run_after(300, {
+///     let fired = fired.clone();
+///     move || fired.set(true)
+/// });
+/// // Native targets have no browser timer: the callback already ran.
+/// assert!(fired.get());
+/// ```
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub fn run_after(delay_ms: u32, callback: impl FnOnce() + 'static) -> Timer {
+    #[cfg(target_arch = "wasm32")]
+    {
+        Timer {
+            timeout: gloo_timers::callback::Timeout::new(delay_ms, callback),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = delay_ms;
+        callback();
+        Timer {}
+    }
+}
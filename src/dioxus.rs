@@ -1,13 +1,40 @@
 #![doc = include_str!("../DIOXUS.md")]
 
-use crate::common::{Animation, Direction, Theme, Variant};
+use crate::common::{
+    Animation, AttachedSkeleton, ColorSchemeListener, DEFAULT_PULSE_MIN_OPACITY, Direction, Easing,
+    FocusOutline, GLOW_KEYFRAMES, GroupDirection, HoverFilter, INFER_SIZE_PROBE_STYLE,
+    KeyframeStep, LIST_ITEM_LAYOUT, LIST_ITEM_TEXT_STYLE, LOW_POWER_SLOWDOWN, MirrorBox,
+    NOSCRIPT_STYLE_ID, OVERLAY_HIGHLIGHT, PULSE_DURATION_MS, PULSE_KEYFRAMES, PulseMode,
+    REVALIDATING_FILL_STYLE, REVALIDATING_OVERLAY_STYLE, RevealScheduler, SHIMMER_KEYFRAMES,
+    SR_ONLY_STYLE, SkeletonConfig, SkeletonDefaults, SkeletonId, StyleParams, Theme, Timer,
+    Variant, WaveImpl, animation_class, aria_live, auto_contrast_color, build_class_names,
+    build_skeleton_style, card_blocks, clamped_lines, compile_keyframes, css_time_ms,
+    global_sync_delay, glow_declaration, inject_animation_class, inject_base_stylesheet,
+    inject_style, inject_style_in, inline_animation, is_decorative, low_power_style,
+    measure_mirror, mirror_box_style, mount_visible, next_instance_id, next_unique_id,
+    noscript_pulse_css, noscript_shared_css, now_ms, paragraph_blocks, parse_template,
+    prefers_dark_scheme, pulse_declaration, pulse_gradient_declaration, quote_blocks, radius_for,
+    run_after, runtime_styles_enabled, scoped_animation_css, set_style_nonce, shimmer_declaration,
+    table_layout, template_layout, text_line_blocks, text_line_widths, text_lines_layout,
+    trace_lifecycle, uses_static_styles, visibility_change, watch_color_scheme, wave_declaration,
+    wave_duration, wave_duration_ms,
+};
+use dioxus::dioxus_core::{DynamicNode, VComponent};
 use dioxus::prelude::*;
-use gloo_timers::callback::Timeout;
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 use web_sys::js_sys;
 use web_sys::wasm_bindgen::JsCast;
 use web_sys::wasm_bindgen::prelude::*;
 use web_sys::window;
-use web_sys::{IntersectionObserver, IntersectionObserverEntry};
+use web_sys::{IntersectionObserver, IntersectionObserverEntry, ResizeObserver};
+
+/// `IntersectionObserver` of `animate_on_visible`, kept alive together with its callback.
+type VisibilityObserver = (
+    IntersectionObserver,
+    Closure<dyn FnMut(js_sys::Array, IntersectionObserver)>,
+);
 
 /// Properties for the `Skeleton` component.
 #[derive(Props, PartialEq, Clone)]
@@ -28,32 +55,227 @@ pub struct SkeletonProps {
     /// Animation style applied to the skeleton.
     ///
     /// Controls how the skeleton animates, e.g., pulse, wave, etc.
-    /// Defaults to `Animation::Pulse`.
+    /// Defaults to the enclosing `SkeletonProvider`'s animation, or `Animation::Pulse`.
+    #[props(default)]
+    pub animation: Option<Animation>,
+
+    /// Whether the animation stops for users who prefer reduced motion.
+    ///
+    /// When `true`, the skeleton stays static while the `prefers-reduced-motion: reduce`
+    /// media query matches. Defaults to `true`.
+    #[props(default = true)]
+    pub respect_reduced_motion: bool,
+
+    /// What `Animation::Pulse` varies.
+    ///
+    /// `PulseMode::Color` animates the background color instead of the opacity, so the
+    /// skeleton stays opaque over images or patterned backgrounds. Defaults to
+    /// `PulseMode::Opacity`.
+    #[props(default)]
+    pub pulse_mode: PulseMode,
+
+    /// Lowest opacity reached by the pulse animation, between `0.0` and `1.0`.
+    ///
+    /// Lower values pulse more strongly; values outside the range are clamped. Also applies
+    /// to `Animation::PulseGradient`. Defaults to `0.4`.
+    #[props(default = DEFAULT_PULSE_MIN_OPACITY)]
+    pub pulse_min_opacity: f32,
+
+    /// Optional timing function preset of the animation, e.g. `Easing::Spring`.
+    ///
+    /// Defaults to `None`, which keeps each animation's own timing: `ease-in-out` for
+    /// pulses and `linear` for waves.
+    #[props(default)]
+    pub easing: Option<Easing>,
+
+    /// Optional custom keyframes as `(percent, step)` pairs.
+    ///
+    /// The stops are compiled into a uniquely named `@keyframes` rule that replaces
+    /// `animation`, for full control without writing raw CSS. Defaults to `None`.
     #[props(default)]
-    pub animation: Animation,
+    pub keyframes: Option<Vec<(u8, KeyframeStep)>>,
 
     /// Direction of the animation direction and background color gradient.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s direction, or `Direction::LeftToRight`.
+    #[props(default)]
+    pub direction: Option<Direction>,
+
+    /// How the wave animation is rendered.
+    ///
+    /// `WaveImpl::Transform` slides an overlaid gradient with `translate`, which is usually
+    /// smoother on low-end devices than animating `background-position`.
+    /// Defaults to `WaveImpl::BackgroundPosition`.
+    #[props(default)]
+    pub wave_impl: WaveImpl,
+
+    /// Speed of the wave animation expressed in sweeps per second.
+    ///
+    /// Converted into the wave's `animation-duration` (e.g. `2.0` yields `0.5s`).
+    /// Defaults to `None`, which keeps the default `1.6s` sweep.
+    #[props(default)]
+    pub sweeps_per_second: Option<f32>,
+
+    /// Optional duration of one animation cycle, as a CSS time (e.g. `"800ms"`).
+    ///
+    /// Overrides the default `1.5s` pulse and the wave duration, including the one derived
+    /// from `sweeps_per_second`. Defaults to `None`.
+    #[props(default)]
+    pub animation_duration: Option<&'static str>,
+
+    /// Optional delay before the animation starts, as a CSS time (e.g. `"200ms"`).
+    ///
+    /// Useful to stagger the animations of neighboring skeletons. Defaults to `None`.
+    #[props(default)]
+    pub animation_delay: Option<&'static str>,
+
+    /// Optional offset added to the wave gradient's `background-position`, e.g. `"-50%"`.
+    ///
+    /// Shifts the phase of the shimmer along the sweep direction, so adjacent skeletons can
+    /// be staggered precisely. A lower-level complement to `sync_global`. Defaults to `None`.
+    #[props(default)]
+    pub wave_offset: Option<&'static str>,
+
+    /// Whether the animation is aligned to a page-wide timeline.
+    ///
+    /// When `true`, a negative `animation-delay` puts newly mounted skeletons in phase with
+    /// every other synced skeleton, avoiding a random-phase look. Defaults to `false`.
+    #[props(default)]
+    pub sync_global: bool,
+
+    /// Whether the animation is throttled to save battery.
+    ///
+    /// Doubles the cycle length and plays it in a few discrete steps instead of a smooth
+    /// curve, cutting repaints. Defaults to `false`.
     #[props(default)]
-    pub direction: Direction,
+    pub low_power: bool,
 
     /// The theme of the skeleton appearance.
     ///
     /// Allows switching between light or dark themes.
-    /// Defaults to `Theme::Light`.
+    /// Defaults to the enclosing `SkeletonProvider`'s theme, or `Theme::Light`.
     #[props(default)]
-    pub theme: Theme,
+    pub theme: Option<Theme>,
+
+    /// Whether the base color adapts to the background behind the skeleton.
+    ///
+    /// When `true`, the nearest non-transparent ancestor background is sampled after mount
+    /// and a contrasting base color replaces the theme's. Defaults to `false`.
+    #[props(default)]
+    pub auto_contrast: bool,
 
     /// The width of the skeleton.
     ///
-    /// Accepts any valid CSS width value (e.g., `100%`, `200px`, `10rem`). Defaults to `"100%"`.
-    #[props(default = "100%")]
-    pub width: &'static str,
+    /// Accepts any valid CSS width value (e.g., `100%`, `200px`, `10rem`), either as a string
+    /// literal or a computed `String`. Defaults to `"100%"`.
+    ///
+    /// ```rust
+    /// use dioxus::prelude::*;
+    /// use skeleton_rs::dioxus::Skeleton;
+    ///
+    /// fn Widths() -> Element {
+    ///     let owned = String::from("12rem");
+    ///     let columns = 4;
+    ///     rsx! {
+    ///         Skeleton { width: "200px" }
+    ///         Skeleton { width: owned }
+    ///         Skeleton { width: format!("calc(100% / {columns})") }
+    ///     }
+    /// }
+    /// ```
+    #[props(into, default = "100%".to_string())]
+    pub width: String,
 
     /// The height of the skeleton.
     ///
-    /// Accepts any valid CSS height value. Defaults to `"1em"`.
-    #[props(default = "1em")]
-    pub height: &'static str,
+    /// Accepts any valid CSS height value, either as a string literal or a computed `String`.
+    /// Defaults to `"1em"`.
+    #[props(into, default = "1em".to_string())]
+    pub height: String,
+
+    /// Optional CSS aspect ratio of the skeleton, e.g. `"16 / 9"`.
+    ///
+    /// Unless `height` is set explicitly, no height is emitted and the browser derives it
+    /// from the width, so the skeleton fills its container and reserves the right box.
+    /// Defaults to `None`.
+    #[props(default)]
+    pub aspect_ratio: Option<&'static str>,
+
+    /// Whether a zero or empty `width`/`height` falls back to a minimum visible size.
+    ///
+    /// Zero-sized skeletons are invisible; debug builds always log a warning for them.
+    /// Defaults to `false`.
+    #[props(default)]
+    pub zero_size_fallback: bool,
+
+    /// Optional size of an icon placeholder.
+    ///
+    /// Only used with `Variant::Icon`, where it sets both width and height.
+    /// Defaults to `None`, which sizes the icon like a line of text (`1em`).
+    #[props(default)]
+    pub icon_size: Option<&'static str>,
+
+    /// Thickness of a divider line.
+    ///
+    /// Only used with `Variant::Divider`, which renders a full-width line of this height.
+    /// Defaults to `"1px"`.
+    #[props(default = "1px")]
+    pub thickness: &'static str,
+
+    /// Number of quote lines in a testimonial placeholder.
+    ///
+    /// Only used with `Variant::Quote`. Defaults to `3`.
+    #[props(default = 3)]
+    pub quote_lines: usize,
+
+    /// Number of rows, header included, in a table placeholder.
+    ///
+    /// Only used with `Variant::Table`. Defaults to `5`.
+    #[props(default = 5)]
+    pub rows: usize,
+
+    /// Number of columns in a table placeholder.
+    ///
+    /// Only used with `Variant::Table`. Defaults to `4`.
+    #[props(default = 4)]
+    pub columns: usize,
+
+    /// Number of stacked text bars rendered by a `Variant::Text` skeleton.
+    ///
+    /// Values above `1` render a paragraph placeholder of that many bars, each `height`
+    /// tall, capped by `max_lines` when set. Also the number of body lines below the heading
+    /// of a `Variant::Paragraph`. Defaults to `1`.
+    #[props(default = 1)]
+    pub lines: usize,
+
+    /// Gap between the bars of a multi-line text skeleton.
+    ///
+    /// Defaults to `"0.5em"`.
+    #[props(default = "0.5em")]
+    pub line_gap: &'static str,
+
+    /// Optional height of the line box of each bar of a multi-line text skeleton.
+    ///
+    /// Each bar stays `height` tall and is centered in a box of this height, so a paragraph
+    /// with a known line height (e.g. `"1.5em"`) is mimicked exactly; set `line_gap` to `"0"`
+    /// when the line boxes already provide the spacing. Defaults to `None`.
+    #[props(default)]
+    pub line_height_each: Option<&'static str>,
+
+    /// Optional width of the last bar of a multi-line text skeleton.
+    ///
+    /// Defaults to `None`, which renders the last bar at `60%` like the end of a paragraph.
+    #[props(default)]
+    pub last_line_width: Option<&'static str>,
+
+    /// Optional layout template rendered as a composite placeholder, e.g.
+    /// `"avatar | line(80%) line(60%)"`.
+    ///
+    /// `|` separates columns laid out side by side; within a column, whitespace-separated
+    /// shapes (`avatar`, `avatar(size)`, `line`, `line(width)`, `block(width, height)`,
+    /// `button`) are stacked. Takes precedence over `variant`. Defaults to `None`.
+    #[props(default)]
+    pub template: Option<&'static str>,
 
     /// Optional font size for the skeleton text.
     ///
@@ -61,6 +283,14 @@ pub struct SkeletonProps {
     #[props(default)]
     pub font_size: Option<&'static str>,
 
+    /// Optional root font size in pixels, used as the basis for computed `em` sizes.
+    ///
+    /// Sizes the crate derives itself (the default icon size, the zero-size fallback) are
+    /// resolved against this value instead of the element's font. Set it in apps that change
+    /// the root font size. Defaults to `None`, which keeps them in `em`.
+    #[props(default)]
+    pub root_font_size: Option<f32>,
+
     /// Border radius for the skeleton.
     ///
     /// Controls the rounding of the skeleton's corners. Accepts any valid CSS radius.
@@ -68,6 +298,39 @@ pub struct SkeletonProps {
     #[props(default = "4px")]
     pub border_radius: &'static str,
 
+    /// Optional border radius applied only to the top corners.
+    ///
+    /// Overrides the top-left and top-right radius, e.g. for image skeletons sitting atop a card body.
+    #[props(default)]
+    pub border_radius_top: Option<&'static str>,
+
+    /// Optional border radius applied only to the bottom corners.
+    ///
+    /// Overrides the bottom-left and bottom-right radius.
+    #[props(default)]
+    pub border_radius_bottom: Option<&'static str>,
+
+    /// Whether shape and size changes are animated.
+    ///
+    /// When `true`, changing `variant`, `width`, `height`, or the border radius transitions
+    /// smoothly instead of jumping to the new shape. Defaults to `false`.
+    #[props(default)]
+    pub morph: bool,
+
+    /// Whether only a themed outline is rendered, without a background fill.
+    ///
+    /// The outline pulses with `Animation::Pulse`; with `Animation::Wave` a subtle sheen in
+    /// the theme color sweeps across the transparent interior. Defaults to `false`.
+    #[props(default)]
+    pub outline_only: bool,
+
+    /// Whether the skeleton is drawn with a subtle inner shadow.
+    ///
+    /// The shadow is a darker shade of the theme color, so the placeholder reads as a recessed
+    /// content area rather than an empty box. Defaults to `false`.
+    #[props(default = false)]
+    pub inset_shadow: bool,
+
     /// Display property for the skeleton.
     ///
     /// Determines the skeleton's display type (e.g., `inline-block`, `block`). Defaults to `"inline-block"`.
@@ -92,27 +355,119 @@ pub struct SkeletonProps {
     #[props(default = "hidden")]
     pub overflow: &'static str,
 
+    /// Optional number of grid columns spanned by the skeleton.
+    ///
+    /// Emits `grid-column: span N;`, so skeleton items fit into an existing CSS grid layout
+    /// such as a responsive card grid. Defaults to `None`.
+    #[props(default)]
+    pub grid_span: Option<usize>,
+
     /// Margin applied to the skeleton.
     ///
     /// Accepts any valid CSS margin value. Defaults to `""`.
     #[props(default)]
     pub margin: &'static str,
 
+    /// Whether generated margins use logical properties.
+    ///
+    /// When `true`, `margin` is emitted as `margin-block`/`margin-inline`, so its left and right
+    /// sides flip in right-to-left layouts. Defaults to `false`.
+    #[props(default)]
+    pub logical_props: bool,
+
     /// Additional inline styles.
     ///
-    /// Allows you to append arbitrary CSS to the skeleton component. Useful for quick overrides.
+    /// Allows you to append arbitrary CSS to the skeleton component, either as a string literal
+    /// or a computed `String`. Useful for quick overrides.
+    #[props(into, default)]
+    pub custom_style: String,
+
+    /// Optional id of a custom element whose shadow root receives the skeleton stylesheet.
+    ///
+    /// Set it when the skeleton renders inside a web component's open shadow root, which
+    /// document-level styles do not reach. Defaults to `None`.
+    #[props(default)]
+    pub shadow_host: Option<&'static str>,
+
+    /// Whether the animation is emitted in a `<style>` element scoped to this skeleton.
+    ///
+    /// The keyframes are renamed uniquely for the instance and rendered inside the skeleton
+    /// instead of the global stylesheet, fully isolating embedded widgets; the style is
+    /// removed together with the skeleton. Defaults to `false`.
+    #[props(default = false)]
+    pub scoped_styles: bool,
+
+    /// HTML tag of the skeleton element.
+    ///
+    /// With `"button"`, the placeholder is rendered as a disabled `<button>` (with
+    /// `aria-disabled`), matching the semantics of the button it stands in for.
+    /// Defaults to `"div"`.
+    #[props(default = "div")]
+    pub tag: &'static str,
+
+    /// Optional DOM `id` of the skeleton element.
+    ///
+    /// Lets other elements reference the loading region, e.g. via `aria-controls`. Defaults
+    /// to `None`, which generates a unique `skeleton-rs-{n}` id.
+    #[props(default)]
+    pub id: Option<&'static str>,
+
+    /// Optional id of the element describing the loading region.
+    ///
+    /// Rendered as `aria-describedby`. Point the revealed content at the same id so assistive
+    /// technology relates the content to the region it replaced.
+    #[props(default)]
+    pub aria_describedby: Option<&'static str>,
+
+    /// Optional accessible label announcing the loading state, e.g. `"Loading orders"`.
+    ///
+    /// Rendered as `aria-label`. Setting it exposes the skeleton to assistive technology
+    /// instead of hiding it as decoration. Defaults to `None`.
+    #[props(default)]
+    pub loading_label: Option<&'static str>,
+
+    /// Optional text exposed only to assistive technology, e.g.
+    /// `"Loading your 3 most recent orders"`.
+    ///
+    /// Rendered inside the skeleton as a visually hidden element, and marks the skeleton
+    /// `aria-busy="true"`. Defaults to `None`.
+    #[props(default)]
+    pub sr_only_text: Option<&'static str>,
+
+    /// Whether the skeleton is marked `aria-busy="true"`.
+    ///
+    /// Like `loading_label`, this exposes the skeleton as a loading status. Defaults to `false`.
+    #[props(default)]
+    pub aria_busy: bool,
+
+    /// Optional ARIA role of the skeleton element.
+    ///
+    /// Defaults to `None`, which renders `role="presentation"` for decorative skeletons and
+    /// `role="status"` once `loading_label` or `aria_busy` is set.
     #[props(default)]
-    pub custom_style: &'static str,
+    pub role: Option<&'static str>,
 
     /// Whether to automatically infer the size from children.
     ///
-    /// If `true`, the skeleton will try to match the dimensions of its content.
+    /// If `true`, the children are rendered invisibly on first render, their
+    /// `getBoundingClientRect()` is measured, and the skeleton takes that size in pixels.
+    /// Without children, or until they are measured, `width` and `height` apply.
     #[props(default)]
     pub infer_size: bool,
 
-    /// Whether the skeleton is currently visible.
+    /// Whether the rendered size is measured once and then fixed.
     ///
-    /// Controls whether the skeleton should be rendered or hidden.
+    /// After the first render the skeleton's box is measured and pinned as explicit pixel
+    /// `width` and `height`, and the `animate_on_visible` observer is disconnected after its
+    /// first intersection, so a static skeleton has no ongoing cost. Defaults to `false`.
+    #[props(default)]
+    pub measure_once: bool,
+
+    /// Whether the content has loaded.
+    ///
+    /// While `false`, the skeleton is rendered in place of the children. Flipping it to `true`
+    /// at runtime removes the skeleton and renders the children; flipping it back shows the
+    /// skeleton again. Defaults to `false`.
     #[props(default)]
     pub show: bool,
 
@@ -122,12 +477,84 @@ pub struct SkeletonProps {
     #[props(default = 0)]
     pub delay_ms: u32,
 
+    /// Controlled visibility of the skeleton, for parents that drive it directly.
+    ///
+    /// When set, the skeleton reflects the signal: it is shown exactly while the signal is
+    /// `true` and the children while it is `false`. `show`, `delay_ms`, `hide_debounce_ms`,
+    /// `min_display_ms`, and `fade_out_ms` are ignored, so the parent can reveal the content
+    /// at any time, even mid-delay. Defaults to `None` (uncontrolled, driven by `show`).
+    #[props(default)]
+    pub visible: Option<Signal<bool>>,
+
+    /// Whether children stay mounted while the skeleton is shown.
+    ///
+    /// When `true`, children are always rendered (hidden while loading) so their component
+    /// state, such as form input, persists across loading cycles. Defaults to `false`.
+    #[props(default)]
+    pub keep_children_mounted: bool,
+
+    /// Duration of the fade-out when the skeleton is revealed, in milliseconds.
+    ///
+    /// When greater than `0`, the skeleton fades out once `show` becomes `true` before the
+    /// children take its place. Defaults to `0` (instant swap).
+    #[props(default = 0)]
+    pub fade_out_ms: u32,
+
+    /// How long `show` must stay `true` before the skeleton is hidden, in milliseconds.
+    ///
+    /// Together with `delay_ms`, which only shows the skeleton once `show` has stayed `false`
+    /// for the whole delay, this coalesces rapid `show` toggles (e.g. on a flaky network)
+    /// instead of flickering. Defaults to `0`.
+    #[props(default = 0)]
+    pub hide_debounce_ms: u32,
+
+    /// Minimum time the skeleton stays on screen once shown, in milliseconds.
+    ///
+    /// When `show` becomes `true` shortly after the skeleton appeared, the hide is postponed
+    /// until it has been visible this long, so fast loads don't flash it for a single frame.
+    /// It only applies once the skeleton is shown: a skeleton mounted with a `delay_ms` starts
+    /// hidden, so data arriving within the delay still skips it entirely. Defaults to `0`.
+    #[props(default = 0)]
+    pub min_display_ms: u32,
+
+    /// Whether children mount only after the fade-out completes.
+    ///
+    /// By default children are mounted (hidden) as soon as the fade-out starts. Enabling this
+    /// defers mounting heavy children until the fade is over, so it doesn't jank.
+    #[props(default)]
+    pub defer_children_until_revealed: bool,
+
+    /// Whether content is being refreshed in the background.
+    ///
+    /// While the skeleton is hidden, children stay visible and a shimmering bar is drawn along
+    /// their top edge, for "stale-while-revalidate" UIs. Defaults to `false`.
+    #[props(default)]
+    pub refreshing: bool,
+
+    /// Whether revealed content is being revalidated.
+    ///
+    /// While the skeleton is hidden, a faint copy of its animation keeps running over the
+    /// children, a subtler indicator than a full skeleton. Defaults to `false`.
+    #[props(default = false)]
+    pub revalidating: bool,
+
     /// Whether the skeleton is responsive.
     ///
-    /// Enables responsive resizing behavior based on the parent container or screen size.
+    /// The skeleton fills its container up to `width`, emitting `width: 100%;
+    /// max-width: <width>; height: auto; aspect-ratio: <ratio>;`. The ratio is
+    /// `aspect_ratio` when set, otherwise `width / height` when both use the same absolute
+    /// unit (e.g. `300px` by `150px` gives `300 / 150`); without a ratio the fixed size is
+    /// kept. Defaults to `false`.
     #[props(default)]
     pub responsive: bool,
 
+    /// Whether the skeleton adapts to its container rather than the viewport.
+    ///
+    /// Wraps the skeleton in a `container-type: inline-size` element and applies `@container`
+    /// rules, so the skeleton stretches to the full width inside narrow containers.
+    #[props(default)]
+    pub container_responsive: bool,
+
     /// Optional maximum width of the skeleton.
     ///
     /// Accepts any valid CSS width value (e.g., `600px`, `100%`).
@@ -152,29 +579,105 @@ pub struct SkeletonProps {
     #[props(default)]
     pub min_height: Option<&'static str>,
 
+    /// Optional maximum number of text lines the skeleton may span.
+    ///
+    /// Caps the height at that many lines and shortens the final line, like `-webkit-line-clamp`,
+    /// including a height measured from the children with `infer_size`. `Some(0)` is ignored.
+    /// Relies on the CSS `lh` unit (Chrome 109, Firefox 120, Safari 16.4).
+    #[props(default)]
+    pub max_lines: Option<usize>,
+
     /// Whether the skeleton animates on hover.
     ///
     /// When enabled, an animation will be triggered when the user hovers over the skeleton.
     #[props(default)]
     pub animate_on_hover: bool,
 
+    /// Optional filter applied on hover when `animate_on_hover` is enabled.
+    ///
+    /// Defaults to `None`, which uses `brightness(0.95)`.
+    #[props(default)]
+    pub hover_filter: Option<HoverFilter>,
+
+    /// Whether the animation pauses while the skeleton is hovered.
+    ///
+    /// Reduces distraction on interactive placeholders. Unlike `animate_on_hover`, this
+    /// doesn't change the skeleton's appearance. Defaults to `false`.
+    #[props(default)]
+    pub pause_on_hover: bool,
+
     /// Whether the skeleton animates on focus.
     ///
     /// Useful for accessibility - triggers animation when the component receives focus.
     #[props(default)]
     pub animate_on_focus: bool,
 
+    /// Optional outline drawn on focus when `animate_on_focus` is enabled.
+    ///
+    /// Defaults to `None`, which uses a `2px solid #999` outline.
+    #[props(default)]
+    pub focus_outline: Option<FocusOutline>,
+
     /// Whether the skeleton animates on active (click or tap).
     ///
     /// Triggers animation when the skeleton is actively clicked or touched.
     #[props(default)]
     pub animate_on_active: bool,
 
+    /// Whether the skeleton is hidden entirely when the page is printed.
+    ///
+    /// By default printed skeletons are rendered static and light instead.
+    #[props(default)]
+    pub hide_on_print: bool,
+
+    /// Whether the skeleton ignores the defaults of a surrounding `SkeletonGroup`.
+    ///
+    /// When `true`, group-level settings such as `overlap` are not applied to this skeleton,
+    /// which keeps only its own props. Defaults to `false`.
+    #[props(default)]
+    pub ignore_group_defaults: bool,
+
     /// Whether the skeleton animates when it becomes visible in the viewport.
     ///
     /// Uses `IntersectionObserver` to detect visibility and trigger animation.
     #[props(default)]
     pub animate_on_visible: bool,
+
+    /// Called once the skeleton first scrolls into view, e.g. to start lazy loading.
+    ///
+    /// Fires at most once per mounted skeleton and requires `animate_on_visible`. Defaults
+    /// to `None`.
+    #[props(default)]
+    pub on_visible: Option<EventHandler<()>>,
+
+    /// Called whenever the skeleton appears or gives way to its children.
+    ///
+    /// Receives the new visibility of the skeleton: `true` once it is shown (e.g. after
+    /// `delay_ms` elapses) and `false` once it is hidden because `show` became `true`. Not
+    /// called for the initial state. Defaults to `None`.
+    #[props(default)]
+    pub on_reveal: Option<EventHandler<bool>>,
+}
+
+/// Builds props from a `SkeletonConfig`, e.g. to render a skeleton from data with
+/// `Skeleton(props)`.
+impl From<SkeletonConfig> for SkeletonProps {
+    fn from(config: SkeletonConfig) -> Self {
+        // No event handlers are set, so the owner of the builder is not needed.
+        let mut props = SkeletonProps::builder().build().inner;
+        props.variant = config.variant;
+        props.animation = config.animation;
+        props.direction = config.direction;
+        props.theme = config.theme;
+        props.width = config.width;
+        props.height = config.height;
+        props.border_radius = config.border_radius;
+        props.lines = config.lines;
+        props.delay_ms = config.delay_ms;
+        props.animate_on_visible = config.animate_on_visible;
+        props.custom_style = config.custom_style;
+        props
+    }
 }
 
 /// Skeleton Component
@@ -266,6 +769,28 @@ pub struct SkeletonProps {
 /// }
 /// ```
 ///
+/// ## Computed Values
+/// ```rust
+/// use dioxus::prelude::*;
+/// use skeleton_rs::dioxus::Skeleton;
+/// use skeleton_rs::Theme;
+///
+/// fn App() -> Element {
+///     let columns = 3;
+///     let height = String::from("2em");
+///     let brand_color = String::from("#cbd5e1");
+///     let offset = 8;
+///     rsx! {
+///         Skeleton { width: "300px", height: "1em" }
+///         Skeleton { width: format!("calc(100% / {columns})"), height: height }
+///         Skeleton {
+///             theme: Theme::CustomOwned(brand_color),
+///             custom_style: format!("margin-left: {offset}px;"),
+///         }
+///     }
+/// }
+/// ```
+///
 /// # Behavior
 /// - With `animate_on_visible`, the animation begins only when the skeleton is in the viewport.
 /// - When `show` is false, the component stays hidden until external or internal logic reveals it.
@@ -283,33 +808,150 @@ pub struct SkeletonProps {
 /// - [MDN IntersectionObserver](https://developer.mozilla.org/en-US/docs/Web/API/Intersection_Observer_API)
 #[component]
 pub fn Skeleton(props: SkeletonProps) -> Element {
-    let mut visible = use_signal(|| !props.show);
-    let id = "skeleton-rs";
+    let controlled = props.visible;
+    let mut visible = use_signal(|| {
+        controlled.map_or_else(
+            || mount_visible(props.show, props.delay_ms),
+            |controlled| *controlled.peek(),
+        )
+    });
+    let mut contrast_color = use_signal(|| None::<&'static str>);
+    let mut fading = use_signal(|| false);
+    // Re-sampled whenever the skeleton element is (re)inserted, which is when its animation starts.
+    let animation_started_at = use_memo(move || {
+        visible();
+        now_ms()
+    });
+    let scope_id = use_hook(next_unique_id);
+    let id = props
+        .id
+        .map_or(SkeletonId::Generated(scope_id), SkeletonId::Given);
+    let instance = use_hook(next_instance_id);
+    let provider = try_use_context::<Signal<SkeletonDefaults>>();
+    // A provider renders the shared `<noscript>` keyframes once for every skeleton below it.
+    let noscript_shared = provider.is_some();
+    let defaults = provider.map(|defaults| defaults()).unwrap_or_default();
+    let theme = defaults.theme(props.theme.as_ref());
+    let animation = defaults.animation(props.animation.as_ref());
+    let direction = defaults.direction(props.direction.as_ref());
+    let reveal_scheduler = try_use_context::<RevealScheduler>()
+        .filter(|scheduler| scheduler.stagger_ms() > 0 && !props.ignore_group_defaults);
+    let mut reveal_delay = use_signal(|| None::<f64>);
 
-    use_effect(move || {
-        if props.show {
-            visible.set(false);
-        } else if props.delay_ms > 0 {
-            Timeout::new(props.delay_ms, move || {
+    let pending_timer = use_hook(|| Rc::new(RefCell::new(None::<Timer>)));
+    let fade_out_ms = props.fade_out_ms;
+    let hide_debounce_ms = props.hide_debounce_ms;
+    let min_display_ms = props.min_display_ms;
+    let delay_ms = props.delay_ms;
+    let show = props.show;
+    use_effect(use_reactive!(|show| {
+        // A flip of `show` cancels the change still pending, including a running fade.
+        if let Some(timer) = pending_timer.borrow_mut().take() {
+            timer.cancel();
+            if *fading.peek() {
+                fading.set(false);
+            }
+        }
+        if let Some(controlled) = controlled {
+            visible.set(controlled());
+            return;
+        }
+        let reveal_scheduler = reveal_scheduler.clone();
+        let reveal = {
+            let pending_timer = pending_timer.clone();
+            move || {
+                let mut schedule_reveal = move || {
+                    if let Some(scheduler) = &reveal_scheduler {
+                        reveal_delay.set(Some(scheduler.next_delay(now_ms())));
+                    }
+                };
+                if fade_out_ms > 0 && *visible.peek() {
+                    fading.set(true);
+                    let fade = run_after(fade_out_ms, move || {
+                        trace_lifecycle!(instance, id, "revealed");
+                        schedule_reveal();
+                        visible.set(false);
+                        fading.set(false);
+                    });
+                    *pending_timer.borrow_mut() = Some(fade);
+                } else {
+                    trace_lifecycle!(instance, id, "revealed");
+                    if *visible.peek() {
+                        schedule_reveal();
+                    }
+                    visible.set(false);
+                }
+            }
+        };
+        let shown_for_ms = visible
+            .peek()
+            .then(|| now_ms() - *animation_started_at.peek());
+        let timer = match visibility_change(
+            show,
+            shown_for_ms,
+            delay_ms,
+            min_display_ms,
+            hide_debounce_ms,
+        ) {
+            (0, false) => {
+                reveal();
+                None
+            }
+            (wait_ms, false) => Some(run_after(wait_ms, reveal)),
+            (0, true) => {
                 visible.set(true);
-            })
-            .forget();
-        } else {
-            visible.set(true);
+                None
+            }
+            (delay_ms, true) => {
+                trace_lifecycle!(instance, id, delay_ms, "delay started");
+                Some(run_after(delay_ms, move || {
+                    trace_lifecycle!(instance, id, delay_ms, "delay elapsed");
+                    visible.set(true);
+                }))
+            }
+        };
+        if let Some(timer) = timer {
+            *pending_timer.borrow_mut() = Some(timer);
+        }
+    }));
+
+    let on_reveal = props.on_reveal;
+    let previous_visible = use_hook(|| Rc::new(Cell::new(*visible.peek())));
+    use_effect(move || {
+        let visible = visible();
+        if previous_visible.replace(visible) != visible {
+            if let Some(on_reveal) = on_reveal {
+                on_reveal.call(visible);
+            }
         }
     });
 
+    let measure_once = props.measure_once;
+    let intersection_observer = use_hook(|| Rc::new(RefCell::new(None::<VisibilityObserver>)));
+    let notified_visible = use_hook(|| Rc::new(Cell::new(false)));
     if props.animate_on_visible {
+        let intersection_observer = intersection_observer.clone();
+        let on_visible = props.on_visible;
         use_effect(move || {
+            let notified_visible = notified_visible.clone();
             let window = web_sys::window().unwrap();
             let document = window.document().unwrap();
-            if let Some(element) = document.get_element_by_id(id) {
+            if let Some(element) = document.get_element_by_id(&id.to_string()) {
                 let closure = Closure::wrap(Box::new(
-                    move |entries: js_sys::Array, _obs: IntersectionObserver| {
+                    move |entries: js_sys::Array, obs: IntersectionObserver| {
                         for entry in entries.iter() {
                             let entry: IntersectionObserverEntry = entry.unchecked_into();
                             if entry.is_intersecting() {
+                                trace_lifecycle!(instance, id, "intersecting");
                                 visible.set(true);
+                                if !notified_visible.replace(true) {
+                                    if let Some(on_visible) = on_visible {
+                                        on_visible.call(());
+                                    }
+                                }
+                                if measure_once {
+                                    obs.disconnect();
+                                }
                             }
                         }
                     },
@@ -318,198 +960,490 @@ pub fn Skeleton(props: SkeletonProps) -> Element {
 
                 let observer = IntersectionObserver::new(closure.as_ref().unchecked_ref()).unwrap();
                 observer.observe(&element);
-                closure.forget();
+                if let Some((previous, _closure)) = intersection_observer
+                    .borrow_mut()
+                    .replace((observer, closure))
+                {
+                    previous.disconnect();
+                }
             }
         });
     }
+    use_drop(move || {
+        if let Some((observer, _closure)) = intersection_observer.borrow_mut().take() {
+            observer.disconnect();
+        }
+    });
 
-    let background_color = match props.theme {
-        Theme::Light => "#e0e0e0",
-        Theme::Dark => "#444444",
-        Theme::Custom(color) => color,
-    };
-
-    let effective_radius = match props.variant {
-        Variant::Circular | Variant::Avatar => "50%",
-        Variant::Rectangular => "0",
-        Variant::Rounded => "8px",
-        Variant::Button => "6px",
-        Variant::Text | Variant::Image => props.border_radius,
-    };
+    let auto_contrast = props.auto_contrast;
+    use_effect(use_reactive!(|auto_contrast| {
+        if !auto_contrast || !visible() {
+            return;
+        }
+        if let Some(element) = window()
+            .and_then(|w| w.document())
+            .and_then(|doc| doc.get_element_by_id(&id.to_string()))
+        {
+            contrast_color.set(auto_contrast_color(&element));
+        }
+    }));
 
-    let animation_style = match props.animation {
-        Animation::Pulse => "animation: skeleton-rs-pulse 1.5s ease-in-out infinite;".to_string(),
-        Animation::Wave => {
-            let angle = match props.direction {
-                Direction::LeftToRight => 90,
-                Direction::RightToLeft => 270,
-                Direction::TopToBottom => 180,
-                Direction::BottomToTop => 0,
-                Direction::CustomAngle(deg) => deg,
-            };
+    let mut measured_size = use_signal(|| None::<(f64, f64)>);
+    use_effect(use_reactive!(|measure_once| {
+        if !measure_once || !visible() || measured_size.peek().is_some() {
+            return;
+        }
+        if let Some(element) = window()
+            .and_then(|w| w.document())
+            .and_then(|doc| doc.get_element_by_id(&id.to_string()))
+        {
+            let rect = element.get_bounding_client_rect();
+            measured_size.set(Some((rect.width(), rect.height())));
+        }
+    }));
 
-            format!(
-                "background: linear-gradient({}deg, #e0e0e0 25%, #f5f5f5 50%, #e0e0e0 75%);
-                 background-size: 200% 100%;
-                 animation: skeleton-rs-wave 1.6s linear infinite;",
-                angle
-            )
+    let mut inferred_size = use_signal(|| None::<(f64, f64)>);
+    let probe_children = props.infer_size
+        && visible()
+        && props.children != VNode::empty()
+        && inferred_size().is_none();
+    use_effect(use_reactive!(|probe_children| {
+        if !probe_children {
+            return;
         }
-        Animation::None => "".to_string(),
+        if let Some(element) = window()
+            .and_then(|w| w.document())
+            .and_then(|doc| doc.get_element_by_id(&format!("{id}-children")))
+        {
+            let rect = element.get_bounding_client_rect();
+            inferred_size.set(Some((rect.width(), rect.height())));
+        }
+    }));
+
+    // Only `Theme::System` follows the OS preference, so no other theme queries it.
+    let system_theme = theme == Theme::System;
+    let mut prefers_dark = use_signal(|| system_theme && prefers_dark_scheme());
+    let color_scheme_listener = use_hook(|| Rc::new(RefCell::new(None::<ColorSchemeListener>)));
+    use_effect(use_reactive!(|system_theme| {
+        *color_scheme_listener.borrow_mut() = system_theme
+            .then(|| watch_color_scheme(move |dark| prefers_dark.set(dark)))
+            .flatten();
+    }));
+
+    let background_color = match contrast_color() {
+        Some(color) => Cow::Borrowed(color),
+        None => theme.scheme_color(prefers_dark()),
     };
 
-    let mut style = String::new();
-    if props.infer_size {
-        style.push_str(&format!(
-            "background-color: {background_color}; border-radius: {effective_radius}; display: {}; position: {}; overflow: {}; margin: {};",
-            props.display, props.position, props.overflow, props.margin
-        ));
+    let effective_radius = radius_for(props.variant, props.border_radius);
+    let (template_layout, template_blocks) = props
+        .template
+        .and_then(|template| match parse_template(template) {
+            Ok(columns) => Some(template_layout(&columns)),
+            Err(err) => {
+                tracing::warn!("skeleton-rs: invalid template {template:?}: {err}");
+                None
+            }
+        })
+        .or_else(|| {
+            (props.variant == Variant::Table)
+                .then(|| table_layout(props.rows, props.columns, effective_radius))
+        })
+        .unzip();
+    let body_lines = clamped_lines(props.lines, props.max_lines);
+    let text_lines = (props.variant == Variant::Text)
+        .then_some(body_lines)
+        .filter(|lines| *lines > 1);
+
+    let angle = direction.gradient_angle();
+
+    // Checkerboards and outlines keep their own background and shimmer through an overlay.
+    let overlay_shimmer = (props.variant == Variant::Checkerboard
+        || props.outline_only
+        || props.wave_impl == WaveImpl::Transform)
+        && animation == Animation::Wave;
+    let overlay_highlight = if props.outline_only {
+        "var(--skeleton-rs-base)"
     } else {
-        style.push_str(&format!(
-            "width: {}; height: {}; background-color: {background_color}; border-radius: {effective_radius}; display: {}; position: {}; overflow: {}; margin: {}; line-height: {};",
-            props.width, props.height, props.display, props.position, props.overflow, props.margin, props.line_height
-        ));
-    }
+        OVERLAY_HIGHLIGHT
+    };
+    let delay = props
+        .animation_delay
+        .map_or_else(String::new, |delay| format!(" {delay}"));
+    let pulse_timing = format!(
+        "{} {}{delay}",
+        props.animation_duration.unwrap_or("1.5s"),
+        props.easing.map_or("ease-in-out", Easing::timing_function)
+    );
+    let wave_timing = format!(
+        "{} {}{delay}",
+        props
+            .animation_duration
+            .map_or_else(|| wave_duration(props.sweeps_per_second), str::to_string),
+        props.easing.map_or("linear", Easing::timing_function)
+    );
+    let mut shimmer_overlay_style = overlay_shimmer.then(|| match props.wave_impl {
+        WaveImpl::Transform => format!(
+            "position: absolute; inset: 0; background: linear-gradient({angle}deg, transparent 25%, {overlay_highlight} 50%, transparent 75%); will-change: transform; animation: {} {wave_timing} infinite;",
+            direction.transform_wave_keyframes()
+        ),
+        WaveImpl::BackgroundPosition => format!(
+            "position: absolute; inset: 0; background: linear-gradient({angle}deg, transparent 25%, {overlay_highlight} 50%, transparent 75%); background-size: {}; animation: {} {wave_timing} infinite;",
+            direction.wave_background_size(),
+            direction.wave_keyframes_name()
+        ),
+    });
 
-    if let Some(size) = props.font_size {
-        style.push_str(&format!(" font-size: {size};"));
-    }
-    if let Some(max_w) = props.max_width {
-        style.push_str(&format!(" max-width: {max_w};"));
-    }
-    if let Some(min_w) = props.min_width {
-        style.push_str(&format!(" min-width: {min_w};"));
-    }
-    if let Some(max_h) = props.max_height {
-        style.push_str(&format!(" max-height: {max_h};"));
-    }
-    if let Some(min_h) = props.min_height {
-        style.push_str(&format!(" min-height: {min_h};"));
+    let animation_style = match animation {
+        Animation::Wave if overlay_shimmer => String::new(),
+        Animation::Pulse => pulse_declaration(props.pulse_mode.keyframes_name(), &pulse_timing),
+        Animation::Wave => wave_declaration(&direction, &wave_timing),
+        Animation::PulseGradient => pulse_gradient_declaration(angle, &pulse_timing),
+        Animation::Shimmer => shimmer_declaration(&direction, &wave_timing),
+
+        Animation::Glow => glow_declaration(&pulse_timing),
+        Animation::None => "".to_string(),
+    };
+    let custom_keyframes = props
+        .keyframes
+        .as_deref()
+        .filter(|steps| !steps.is_empty())
+        .map(compile_keyframes);
+    let duration_ms = props.animation_duration.and_then(css_time_ms);
+    let animation_ms = match (&custom_keyframes, &animation, duration_ms) {
+        (None, Animation::None, _) => 0.0,
+        (_, _, Some(ms)) => ms,
+        (Some(_), _, None)
+        | (None, Animation::Pulse | Animation::PulseGradient | Animation::Glow, None) => {
+            PULSE_DURATION_MS
+        }
+        (None, Animation::Wave | Animation::Shimmer, None) => {
+            wave_duration_ms(props.sweeps_per_second)
+        }
+    };
+    let (mut animation_style, keyframes_css) = match custom_keyframes {
+        Some((name, css)) => (format!("animation: {name} {pulse_timing} infinite;"), css),
+        None => (animation_style, String::new()),
+    };
+    if props.low_power {
+        let throttle = low_power_style(animation_ms);
+        if !animation_style.is_empty() {
+            animation_style.push_str(&throttle);
+        }
+        if let Some(overlay_style) = &mut shimmer_overlay_style {
+            overlay_style.push_str(&throttle);
+        }
     }
+    let animation_ms = if props.low_power {
+        animation_ms * LOW_POWER_SLOWDOWN
+    } else {
+        animation_ms
+    };
 
-    style.push_str(&animation_style);
-    style.push_str(props.custom_style);
+    let sync_delay = props
+        .sync_global
+        .then(|| global_sync_delay(animation_ms, animation_started_at()));
+    let style_params = StyleParams {
+        variant: props.variant,
+        background_color: &background_color,
+        highlight_color: theme.highlight(),
+        pulse_min_opacity: props.pulse_min_opacity,
+        border_radius: effective_radius,
+        border_radius_top: props.border_radius_top,
+        border_radius_bottom: props.border_radius_bottom,
+        width: props.width.as_str(),
+        height: props.height.as_str(),
+        aspect_ratio: props.aspect_ratio,
+        infer_size: props.infer_size && inferred_size().is_some(),
+        zero_size_fallback: props.zero_size_fallback,
+        responsive: props.responsive,
+        root_font_size: props.root_font_size,
+        icon_size: props.icon_size,
+        thickness: props.thickness,
+        display: props.display,
+        position: props.position,
+        overflow: props.overflow,
+        margin: props.margin,
+        logical_props: props.logical_props,
+        line_height: props.line_height,
+        font_size: props.font_size,
+        max_width: props.max_width,
+        min_width: props.min_width,
+        max_height: props.max_height,
+        min_height: props.min_height,
+        grid_span: props.grid_span,
+        multi_line: text_lines.is_some(),
+        line_gap: props.line_gap,
+        max_lines: props.max_lines,
+        template_layout: template_layout.as_deref(),
+        tag: props.tag,
+        outline_only: props.outline_only,
+        inset_shadow: props.inset_shadow,
+        sync_delay: sync_delay.as_deref(),
+        measured_size: measured_size().or(inferred_size()),
+        wave_offset: props.wave_offset,
+        hover_filter: props.hover_filter.as_ref(),
+        focus_outline: props.focus_outline.as_ref(),
+        morph: props.morph,
+        custom_style: &props.custom_style,
+        fading_ms: fading().then_some(props.fade_out_ms),
+        animate_on_hover: props.animate_on_hover,
+        pause_on_hover: props.pause_on_hover,
+        respect_reduced_motion: props.respect_reduced_motion,
+        animate_on_focus: props.animate_on_focus,
+        animate_on_active: props.animate_on_active,
+        hide_on_print: props.hide_on_print,
+        container_responsive: props.container_responsive,
+        ignore_group_defaults: props.ignore_group_defaults,
+    };
+    let style = build_skeleton_style(&style_params);
 
-    let mut class_names = "skeleton-rs".to_string();
-    if props.animate_on_hover {
-        class_names.push_str(" skeleton-hover");
-    }
-    if props.animate_on_focus {
-        class_names.push_str(" skeleton-focus");
-    }
-    if props.animate_on_active {
-        class_names.push_str(" skeleton-active");
-    }
+    let wave_keyframes = direction.wave_keyframes();
 
-    let direction = props.direction.clone();
-    use_effect(move || {
-        let window = window().unwrap();
-        let document = window.document().unwrap();
-        if document.get_element_by_id("skeleton-rs-style").is_none() {
-            let style_elem = document.create_element("style").unwrap();
-            style_elem.set_id("skeleton-rs-style");
-
-            let wave_keyframes = match direction {
-                Direction::LeftToRight => {
-                    r#"
-                        @keyframes skeleton-rs-wave {
-                            0%   { background-position: 200% 0; }
-                            25%  { background-position: 100% 0; }
-                            50%  { background-position: 0% 0; }
-                            75%  { background-position: -100% 0; }
-                            100% { background-position: -200% 0; }
-                        }"#
-                }
-                Direction::RightToLeft => {
-                    r#"
-                        @keyframes skeleton-rs-wave {
-                            0%   { background-position: -200% 0; }
-                            25%  { background-position: -100% 0; }
-                            50%  { background-position: 0% 0; }
-                            75%  { background-position: 100% 0; }
-                            100% { background-position: 200% 0; }
-                        }"#
-                }
-                Direction::TopToBottom => {
-                    r#"
-                        @keyframes skeleton-rs-wave {
-                            0%   { background-position: 0 -200%; }
-                            25%  { background-position: 0 -100%; }
-                            50%  { background-position: 0 0%; }
-                            75%  { background-position: 0 100%; }
-                            100% { background-position: 0 200%; }
-                        }"#
-                }
-                Direction::BottomToTop => {
-                    r#"
-                        @keyframes skeleton-rs-wave {
-                            0%   { background-position: 0 200%; }
-                            25%  { background-position: 0 100%; }
-                            50%  { background-position: 0 0%; }
-                            75%  { background-position: 0 -100%; }
-                            100% { background-position: 0 -200%; }
-                        }"#
-                }
-                Direction::CustomAngle(_) => {
-                    r#"
-                        @keyframes skeleton-rs-wave {
-                            0%   { background-position: 200% 0; }
-                            25%  { background-position: 100% 0; }
-                            50%  { background-position: 0% 0; }
-                            75%  { background-position: -100% 0; }
-                            100% { background-position: -200% 0; }
-                        }"#
-                }
+    let mut anim_class = (!animation_style.is_empty()).then(|| animation_class(&animation_style));
+    let scoped_css = match &mut anim_class {
+        Some(class) if props.scoped_styles => {
+            let keyframes = match animation {
+                _ if !keyframes_css.is_empty() => keyframes_css.as_str(),
+                Animation::Pulse => props.pulse_mode.keyframes(),
+                Animation::PulseGradient => PULSE_KEYFRAMES,
+                Animation::Wave => &wave_keyframes,
+                Animation::Shimmer => SHIMMER_KEYFRAMES,
+                Animation::Glow => GLOW_KEYFRAMES,
+                Animation::None => "",
             };
+            let (scoped_class, css) = scoped_animation_css(scope_id, keyframes, &animation_style);
+            *class = scoped_class;
+            Some(css)
+        }
+        _ => None,
+    };
+    // Composite placeholders animate their blocks instead of the transparent container.
+    let blocks = match props.variant {
+        _ if template_blocks.is_some() => template_blocks,
+        Variant::Quote => Some(quote_blocks(props.quote_lines)),
+        Variant::Card => Some(card_blocks(effective_radius)),
+        Variant::Paragraph => Some(paragraph_blocks(
+            body_lines,
+            props.height.as_str(),
+            props.line_height_each,
+            props.last_line_width.unwrap_or("60%"),
+            effective_radius,
+        )),
+        _ => text_lines.map(|lines| {
+            text_line_blocks(
+                lines,
+                props.height.as_str(),
+                props.line_height_each,
+                props.last_line_width.unwrap_or("60%"),
+                effective_radius,
+            )
+        }),
+    };
+    let block_class = blocks.as_ref().and(anim_class.clone());
+    let revalidating_class = anim_class.clone().filter(|_| props.revalidating);
+    let class_names = build_class_names(
+        &style_params,
+        anim_class.as_deref().filter(|_| blocks.is_none()),
+    );
+    // Without runtime styles, a generated class has no rule and its animation is inlined.
+    let inline_anim = inline_animation(
+        anim_class.as_deref().filter(|_| scoped_css.is_none()),
+        &animation_style,
+    );
+    let style = if blocks.is_none() {
+        format!("{style}{inline_anim}")
+    } else {
+        style
+    };
+    let noscript_css = match (&animation, &anim_class) {
+        // Apps without runtime styles ship the keyframes in their own stylesheet.
+        _ if scoped_css.is_some() || !runtime_styles_enabled() => None,
+        (_, Some(anim_class)) if !keyframes_css.is_empty() => Some(format!(
+            "{keyframes_css} .{anim_class} {{ {animation_style} }}"
+        )),
+        (Animation::Pulse | Animation::PulseGradient, Some(anim_class)) => {
+            noscript_pulse_css(anim_class, &animation_style, noscript_shared)
+        }
+        _ => None,
+    };
 
-            let css = format!(
-                r#"
-                        @keyframes skeleton-rs-pulse {{
-                            0% {{ opacity: 1; }}
-                            25% {{ opacity: 0.7; }}
-                            50% {{ opacity: 0.4; }}
-                            75% {{ opacity: 0.7; }}
-                            100% {{ opacity: 1; }}
-                        }}
-
-                        {}
-
-                        .skeleton-hover:hover {{
-                            filter: brightness(0.95);
-                        }}
-
-                        .skeleton-focus:focus {{
-                            outline: 2px solid #999;
-                        }}
-
-                        .skeleton-active:active {{
-                            transform: scale(0.98);
-                        }}
-                    "#,
-                wave_keyframes
+    let shadow_host = props.shadow_host;
+    let scoped = scoped_css.is_some();
+    // Styles are injected while rendering rather than from an effect, so the skeleton is
+    // animated from its first frame. Each direction has its own wave keyframes, so waves of
+    // several directions can coexist.
+    let wave_direction = (animation == Animation::Wave).then(|| direction.clone());
+    use_hook(|| inject_base_stylesheet(shadow_host));
+    let injected = use_hook(|| Rc::new(RefCell::new((None::<String>, None::<Direction>))));
+    if injected.borrow().0 != anim_class {
+        if let Some(anim_class) = anim_class.as_ref().filter(|_| !scoped) {
+            inject_animation_class(shadow_host, anim_class, &keyframes_css, &animation_style);
+        }
+        injected.borrow_mut().0 = anim_class.clone();
+    }
+    if injected.borrow().1 != wave_direction {
+        if let Some(direction) = wave_direction
+            .as_ref()
+            .filter(|_| !uses_static_styles(shadow_host))
+        {
+            inject_style_in(
+                shadow_host,
+                &direction.wave_keyframes_name(),
+                &direction.wave_keyframes(),
             );
-
-            style_elem.set_inner_html(&css);
-            if let Some(head) = document.head() {
-                head.append_child(&style_elem).unwrap();
-            }
         }
+        injected.borrow_mut().1 = wave_direction;
+    }
+    use_effect(move || {
+        trace_lifecycle!(instance, id, "mounted");
     });
 
-    if visible() {
+    let is_button = props.tag == "button";
+    let busy = props.aria_busy || props.sr_only_text.is_some();
+    let decorative = !is_button && is_decorative(props.loading_label, busy, props.role);
+    let role =
+        props
+            .role
+            .or((!is_button).then_some(if decorative { "presentation" } else { "status" }));
+    let aria_hidden = decorative.then_some("true");
+    let aria_busy = busy.then_some("true");
+    let aria_live = aria_live(role);
+
+    let content = rsx! {
+        if let Some(css) = scoped_css {
+            style { "{css}" }
+        }
+        if let Some(blocks) = blocks {
+            for block in blocks {
+                span { class: block_class.clone(), style: "{block}{inline_anim}" }
+            }
+        }
+        if let Some(overlay_style) = shimmer_overlay_style {
+            span { style: "{overlay_style}" }
+        }
+        if let Some(text) = props.sr_only_text {
+            span { style: SR_ONLY_STYLE, "{text}" }
+        }
+        if let Some(css) = noscript_css {
+            noscript { style { "{css}" } }
+        }
+    };
+    let skeleton = if is_button {
+        rsx! {
+            button {
+                id: "{id}",
+                class: "{class_names}",
+                style: "{style}",
+                r#type: "button",
+                disabled: true,
+                aria_disabled: "true",
+                role: role,
+                aria_busy: aria_busy,
+                aria_live: aria_live,
+                aria_label: props.loading_label,
+                aria_describedby: props.aria_describedby,
+                "data-variant": props.variant.as_str(),
+                "data-animation": animation.as_str(),
+                "data-theme": theme.as_str(),
+                {content}
+            }
+        }
+    } else {
         rsx! {
             div {
                 id: "{id}",
                 class: "{class_names}",
                 style: "{style}",
-                role: "presentation",
-                aria_hidden: "true"
+                role: role,
+                aria_hidden: aria_hidden,
+                aria_busy: aria_busy,
+                aria_live: aria_live,
+                aria_label: props.loading_label,
+                aria_describedby: props.aria_describedby,
+                "data-variant": props.variant.as_str(),
+                "data-animation": animation.as_str(),
+                "data-theme": theme.as_str(),
+                {content}
+            }
+        }
+    };
+    let skeleton = if props.container_responsive {
+        rsx! {
+            div {
+                class: "skeleton-rs-container",
+                style: "container-type: inline-size;",
+                {skeleton}
             }
         }
     } else {
+        skeleton
+    };
+    let skeleton = if probe_children {
         rsx! {
-            {props.children}
+            {skeleton}
+            div { id: "{id}-children", style: INFER_SIZE_PROBE_STYLE, {props.children.clone()} }
         }
+    } else {
+        skeleton
+    };
+
+    let highlight_var = theme.highlight().map_or_else(String::new, |highlight| {
+        format!(" --skeleton-rs-highlight: {highlight};")
+    });
+    let children = if props.refreshing || revalidating_class.is_some() {
+        rsx! {
+            div {
+                class: props.refreshing.then_some("skeleton-refreshing"),
+                style: "position: relative; --skeleton-rs-base: {background_color};{highlight_var}",
+                aria_busy: "true",
+                {props.children}
+                if let Some(class) = revalidating_class {
+                    span { style: REVALIDATING_OVERLAY_STYLE,
+                        span { class, style: "{REVALIDATING_FILL_STYLE}{inline_anim}" }
+                    }
+                }
+            }
+        }
+    } else {
+        props.children
+    };
+
+    let children = match reveal_delay() {
+        Some(delay) => rsx! {
+            div {
+                class: "skeleton-reveal",
+                style: "animation-delay: {delay:.0}ms;",
+                {children}
+            }
+        },
+        None => children,
+    };
+
+    if props.keep_children_mounted || props.fade_out_ms > 0 {
+        let mount_children = props.keep_children_mounted
+            || !visible()
+            || (fading() && !props.defer_children_until_revealed);
+        let children_style = if visible() {
+            "display: none;"
+        } else {
+            "display: contents;"
+        };
+        rsx! {
+            if visible() {
+                {skeleton}
+            }
+            if mount_children {
+                div {
+                    style: "{children_style}",
+                    {children}
+                }
+            }
+        }
+    } else if visible() {
+        skeleton
+    } else {
+        children
     }
 }
 
@@ -523,15 +1457,523 @@ pub struct SkeletonGroupProps {
 
     #[props(default)]
     pub class: &'static str,
+
+    /// Optional amount by which consecutive children overlap, e.g. `"12px"`.
+    ///
+    /// Applies a negative left margin to every child after the first, so grouped
+    /// skeletons stack like a fanned deck of cards.
+    #[props(default)]
+    pub overlap: Option<&'static str>,
+
+    /// Whether `overlap` uses logical margins.
+    ///
+    /// When `true`, children overlap along the inline direction, so the stack flips in
+    /// right-to-left layouts. Defaults to `false`.
+    #[props(default)]
+    pub logical_props: bool,
+
+    /// Optional pair of base colors alternated between odd and even children.
+    ///
+    /// Zebra-stripes long list placeholders for readability; the first color applies to
+    /// odd children. Defaults to `None`.
+    #[props(default)]
+    pub stripe_colors: Option<(&'static str, &'static str)>,
+
+    /// Minimum delay between the reveals of consecutive children, in milliseconds.
+    ///
+    /// When greater than `0`, each child fades its content in as its `show` flips, at least
+    /// this long after the previous one, for a smooth streaming-load effect. Defaults to `0`.
+    #[props(default)]
+    pub reveal_stagger_ms: u32,
+
+    /// Optional name of a stagger timeline shared with other groups.
+    ///
+    /// Groups with the same timeline reveal their children as one coordinated sequence,
+    /// continuing the stagger across groups. Defaults to `None`.
+    #[props(default)]
+    pub timeline: Option<&'static str>,
+
+    /// Space between children, as a CSS length or a spacing token, e.g. `Spacing::Md`.
+    ///
+    /// Tokens resolve through the scale registered with `register_spacing_scale`, so
+    /// grouped skeletons follow the app's spacing tokens. Applies when `direction` is set
+    /// and between repeated `template` items. Defaults to `"0.5rem"`.
+    #[props(default = "0.5rem", into)]
+    pub gap: &'static str,
+
+    /// Optional main axis of a flexbox layout, e.g. `GroupDirection::Column`.
+    ///
+    /// When set, the container becomes a flex container spaced by `gap`; `style` is
+    /// appended afterwards and can override it. Defaults to `None`.
+    #[props(default)]
+    pub direction: Option<GroupDirection>,
+
+    /// Optional props of a skeleton repeated `count` times after the children.
+    ///
+    /// Renders lists of identical rows without writing each one out, e.g.
+    /// `SkeletonConfig::new().width("100%").into()`. Repeated items are spaced by `gap`.
+    /// Defaults to `None`.
+    #[props(default)]
+    pub template: Option<SkeletonProps>,
+
+    /// Number of copies of `template` rendered.
+    ///
+    /// Ignored without a `template`. Defaults to `1`.
+    #[props(default = 1)]
+    pub count: usize,
 }
 
 #[component]
 pub fn SkeletonGroup(props: SkeletonGroupProps) -> Element {
+    let reveal_scheduler = use_context_provider(|| match props.timeline {
+        Some(timeline) => RevealScheduler::shared(timeline, props.reveal_stagger_ms),
+        None => RevealScheduler::new(props.reveal_stagger_ms),
+    });
+    reveal_scheduler.set_stagger_ms(props.reveal_stagger_ms);
+
+    let mut class_names = props.class.to_string();
+    let mut style = String::new();
+    if let Some(overlap) = props.overlap {
+        class_names.push_str(" skeleton-group-overlap");
+        if props.logical_props {
+            class_names.push_str(" skeleton-group-logical");
+        }
+        style.push_str(&format!("--skeleton-group-overlap: {overlap};"));
+    }
+    if let Some((odd, even)) = props.stripe_colors {
+        class_names.push_str(" skeleton-group-striped");
+        style.push_str(&format!(
+            "--skeleton-group-stripe-odd: {odd}; --skeleton-group-stripe-even: {even};"
+        ));
+    }
+    match props.direction {
+        Some(direction) => style.push_str(&format!(
+            "display: flex; flex-direction: {}; gap: {};",
+            direction.css_value(),
+            props.gap
+        )),
+        None if props.template.is_some() => {
+            style.push_str(&format!("display: grid; gap: {};", props.gap));
+        }
+        None => {}
+    }
+    style.push_str(props.style);
+
     rsx! {
         div {
-            class: "{props.class}",
-            style: "{props.style}",
+            class: "{class_names}",
+            style: "{style}",
             {props.children}
+            if let Some(template) = props.template {
+                for _ in 0..props.count {
+                    {DynamicNode::Component(VComponent::new(Skeleton, template.clone(), "Skeleton"))}
+                }
+            }
         }
     }
 }
+
+#[derive(Props, PartialEq, Clone)]
+pub struct SkeletonProviderProps {
+    /// Theme of the skeletons inside the provider that do not set `theme`.
+    #[props(default)]
+    pub theme: Option<Theme>,
+
+    /// Animation of the skeletons inside the provider that do not set `animation`.
+    #[props(default)]
+    pub animation: Option<Animation>,
+
+    /// Direction of the skeletons inside the provider that do not set `direction`.
+    #[props(default)]
+    pub direction: Option<Direction>,
+
+    /// CSP nonce set as the `nonce` attribute of every `<style>` element the skeletons inject,
+    /// for pages served with a `style-src 'nonce-...'` Content-Security-Policy.
+    #[props(default, into)]
+    pub nonce: Option<String>,
+
+    pub children: Element,
+}
+
+/// Supplies app-wide defaults to every skeleton rendered inside it.
+///
+/// Skeletons resolve `theme`, `animation`, and `direction` with the precedence explicit
+/// prop > provider > enum default. Nested providers inherit what they do not set from the
+/// enclosing one, and changing a provider prop at runtime updates the skeletons below it.
+///
+/// # Examples
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use skeleton_rs::dioxus::{Skeleton, SkeletonProvider};
+/// use skeleton_rs::{Animation, Theme};
+///
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         SkeletonProvider { theme: Theme::Dark, animation: Animation::Wave,
+///             Skeleton { width: "200px" }
+///             Skeleton { width: "120px", theme: Theme::Light }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn SkeletonProvider(props: SkeletonProviderProps) -> Element {
+    // Set while rendering, so it is in place before the skeletons below inject their styles.
+    if let Some(nonce) = &props.nonce {
+        set_style_nonce(nonce);
+    }
+    let outer = try_use_context::<Signal<SkeletonDefaults>>();
+    // Only the outermost provider ships the `<noscript>` keyframes.
+    let noscript_css = (outer.is_none() && runtime_styles_enabled()).then(noscript_shared_css);
+    let outer = outer.map(|outer| outer()).unwrap_or_default();
+    let defaults = SkeletonDefaults {
+        theme: props.theme,
+        animation: props.animation,
+        direction: props.direction,
+    }
+    .inherit(&outer);
+    let mut context = use_context_provider(|| Signal::new(defaults.clone()));
+    use_effect(use_reactive!(|defaults| {
+        if *context.peek() != defaults {
+            context.set(defaults);
+        }
+    }));
+
+    rsx! {
+        if let Some(css) = noscript_css {
+            noscript {
+                style { id: NOSCRIPT_STYLE_ID, nonce: props.nonce.clone(), "{css}" }
+            }
+        }
+        {props.children}
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct SkeletonListItemProps {
+    /// Width and height of the circular avatar.
+    ///
+    /// Defaults to `"2.5em"`.
+    #[props(default = "2.5em")]
+    pub avatar_size: &'static str,
+
+    /// Number of text lines stacked next to the avatar, e.g. a name and a subtitle.
+    ///
+    /// The last line is drawn shorter. Defaults to `2`.
+    #[props(default = 2)]
+    pub lines: usize,
+
+    /// The theme of the avatar and text skeletons.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s theme, or `Theme::Light`.
+    #[props(default)]
+    pub theme: Option<Theme>,
+
+    /// The animation of the avatar and text skeletons.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s animation, or `Animation::Pulse`.
+    #[props(default)]
+    pub animation: Option<Animation>,
+
+    #[props(default)]
+    pub style: &'static str,
+
+    #[props(default)]
+    pub class: &'static str,
+}
+
+/// List row placeholder: a circular avatar next to stacked text lines.
+///
+/// Composes an `Avatar` and a `Text` skeleton, e.g. for chat or contact lists.
+///
+/// # Examples
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use skeleton_rs::dioxus::SkeletonListItem;
+///
+/// #[component]
+/// fn Contacts() -> Element {
+///     rsx! {
+///         for _ in 0..4 {
+///             SkeletonListItem { avatar_size: "3em" }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn SkeletonListItem(props: SkeletonListItemProps) -> Element {
+    rsx! {
+        div {
+            class: props.class,
+            style: "{LIST_ITEM_LAYOUT}{props.style}",
+            Skeleton {
+                variant: Variant::Avatar,
+                width: props.avatar_size,
+                height: props.avatar_size,
+                theme: props.theme.clone(),
+                animation: props.animation.clone(),
+            }
+            Skeleton {
+                variant: Variant::Text,
+                lines: props.lines,
+                width: "100%",
+                height: "0.9em",
+                display: "block",
+                theme: props.theme,
+                animation: props.animation,
+                custom_style: LIST_ITEM_TEXT_STYLE,
+            }
+        }
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct SkeletonTextProps {
+    /// Number of lines in the paragraph.
+    ///
+    /// Defaults to `3`.
+    #[props(default = 3)]
+    pub lines: usize,
+
+    /// Seed of the line widths, see `text_line_widths`.
+    ///
+    /// Paragraphs with different seeds get different widths; the same seed always renders the
+    /// same widths, on the server and in the browser. Defaults to `None` (seed `0`).
+    #[props(default)]
+    pub seed: Option<u64>,
+
+    /// Explicit width of each line, overriding the generated ones.
+    ///
+    /// Lines without an entry keep their generated width. Defaults to `None`.
+    #[props(default)]
+    pub widths: Option<Vec<String>>,
+
+    /// Height of each line.
+    ///
+    /// Defaults to `"1em"`.
+    #[props(default = "1em")]
+    pub height: &'static str,
+
+    /// Gap between the lines.
+    ///
+    /// Defaults to `"0.5em"`.
+    #[props(default = "0.5em")]
+    pub line_gap: &'static str,
+
+    /// The theme of the lines.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s theme, or `Theme::Light`.
+    #[props(default)]
+    pub theme: Option<Theme>,
+
+    /// The animation of the lines.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s animation, or `Animation::Pulse`.
+    #[props(default)]
+    pub animation: Option<Animation>,
+
+    #[props(default)]
+    pub style: &'static str,
+
+    #[props(default)]
+    pub class: &'static str,
+}
+
+/// Paragraph placeholder whose lines vary in width like real text.
+///
+/// Every line is a `Text` skeleton whose width comes from `text_line_widths`, so the
+/// paragraph looks natural while rendering identically on the server and the client.
+///
+/// # Examples
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use skeleton_rs::dioxus::SkeletonText;
+///
+/// #[component]
+/// fn Article() -> Element {
+///     rsx! {
+///         SkeletonText { lines: 4, seed: 1 }
+///         SkeletonText { lines: 2, widths: vec!["100%".to_string(), "30%".to_string()] }
+///     }
+/// }
+/// ```
+#[component]
+pub fn SkeletonText(props: SkeletonTextProps) -> Element {
+    let widths = text_line_widths(props.lines, props.seed.unwrap_or_default());
+    let widths = widths.into_iter().enumerate().map(|(line, width)| {
+        props
+            .widths
+            .as_ref()
+            .and_then(|widths| widths.get(line).cloned())
+            .unwrap_or(width)
+    });
+    let layout = text_lines_layout(props.line_gap);
+
+    rsx! {
+        div { class: props.class, style: "{layout}{props.style}",
+            for width in widths {
+                Skeleton {
+                    variant: Variant::Text,
+                    width,
+                    height: props.height,
+                    display: "block",
+                    theme: props.theme.clone(),
+                    animation: props.animation.clone(),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct SkeletonMirrorProps {
+    /// Id of the rendered element whose layout is mirrored.
+    pub target_id: &'static str,
+
+    /// The theme of the mirrored placeholders.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s theme, or `Theme::Light`.
+    #[props(default)]
+    pub theme: Option<Theme>,
+
+    /// Border radius of every mirrored placeholder.
+    ///
+    /// Defaults to `"4px"`.
+    #[props(default = "4px")]
+    pub border_radius: &'static str,
+
+    /// Whether the mirrored placeholders pulse.
+    ///
+    /// Defaults to `true`.
+    #[props(default = true)]
+    pub animate: bool,
+}
+
+/// Skeleton mirroring the box structure of an already-rendered element.
+///
+/// Every leaf element of the element with id `target_id` (text blocks, images, buttons, ...)
+/// is measured and replaced by a placeholder of the same size and position, so the loading
+/// state matches the loaded layout exactly. The target is observed with `ResizeObserver` and
+/// re-measured whenever its size changes.
+///
+/// # Examples
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use skeleton_rs::dioxus::SkeletonMirror;
+///
+/// #[component]
+/// fn App() -> Element {
+///     let loading = use_signal(|| true);
+///     rsx! {
+///         div {
+///             id: "card",
+///             hidden: loading(),
+///             h3 { "Title" }
+///             p { "Body" }
+///         }
+///         if loading() {
+///             SkeletonMirror { target_id: "card" }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn SkeletonMirror(props: SkeletonMirrorProps) -> Element {
+    let mut layout = use_signal(|| None::<(MirrorBox, Vec<MirrorBox>)>);
+    let defaults = try_use_context::<Signal<SkeletonDefaults>>()
+        .map(|defaults| defaults())
+        .unwrap_or_default();
+    let observer = use_hook(|| Rc::new(RefCell::new(None::<ResizeObserver>)));
+
+    {
+        let observer = observer.clone();
+        let target_id = props.target_id;
+        use_effect(move || {
+            inject_style("skeleton-rs-mirror-style", PULSE_KEYFRAMES);
+            let Some(element) = window()
+                .and_then(|w| w.document())
+                .and_then(|doc| doc.get_element_by_id(target_id))
+            else {
+                return;
+            };
+            layout.set(Some(measure_mirror(&element)));
+            let observed = element.clone();
+            let cb = Closure::wrap(Box::new(
+                move |_entries: js_sys::Array, _observer: ResizeObserver| {
+                    layout.set(Some(measure_mirror(&observed)));
+                },
+            )
+                as Box<dyn FnMut(js_sys::Array, ResizeObserver)>);
+            if let Ok(resize_observer) = ResizeObserver::new(cb.as_ref().unchecked_ref()) {
+                resize_observer.observe(&element);
+                if let Some(previous) = observer.borrow_mut().replace(resize_observer) {
+                    previous.disconnect();
+                }
+            }
+            cb.forget();
+        });
+    }
+
+    use_drop(move || {
+        if let Some(resize_observer) = observer.borrow_mut().take() {
+            resize_observer.disconnect();
+        }
+    });
+
+    let Some((root, boxes)) = layout() else {
+        return rsx! {};
+    };
+    let base_color = defaults.theme(props.theme.as_ref()).base_color();
+
+    rsx! {
+        div {
+            class: "skeleton-rs-mirror",
+            style: "position: relative; width: {root.width}px; height: {root.height}px;",
+            role: "presentation",
+            aria_hidden: "true",
+            for mirror_box in boxes {
+                div {
+                    class: "skeleton-rs",
+                    style: mirror_box_style(&mirror_box, &base_color, props.border_radius, props.animate),
+                }
+            }
+        }
+    }
+}
+
+/// Returns a class that draws a skeleton over the element it is applied to.
+///
+/// The skeleton is rendered by a `::before` pseudo-element, so no wrapper node is added.
+/// Apply the class while loading and remove it once the content is ready. See
+/// `AttachedSkeleton` for the available options.
+///
+/// # Examples
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use skeleton_rs::dioxus::use_skeleton_class;
+/// use skeleton_rs::AttachedSkeleton;
+///
+/// #[component]
+/// fn Card() -> Element {
+///     let loading = use_signal(|| true);
+///     let skeleton = use_skeleton_class(AttachedSkeleton::default());
+///     let class = if loading() { format!("card {skeleton}") } else { "card".to_string() };
+///     rsx! {
+///         div { class: "{class}", "Content" }
+///     }
+/// }
+/// ```
+pub fn use_skeleton_class(skeleton: AttachedSkeleton) -> String {
+    let (class, css) = skeleton.stylesheet();
+    let injected = class.clone();
+    use_effect(use_reactive!(|(injected, css)| inject_style(
+        &injected, &css
+    )));
+    class
+}
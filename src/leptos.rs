@@ -1 +1,929 @@
+#![doc = include_str!("../LEPTOS.md")]
 
+use crate::common::{
+    Animation, ColorSchemeListener, DEFAULT_PULSE_MIN_OPACITY, Direction, Easing, GroupDirection,
+    PulseMode, SkeletonConfig, SkeletonDefaults, StyleParams, Theme, Timer, Variant,
+    animation_class, aria_live, base_stylesheet, build_class_names, build_skeleton_style,
+    card_blocks, glow_declaration, inject_style, inline_animation, is_decorative,
+    is_preset_animation_class, mount_visible, next_instance_id, now_ms, paragraph_blocks,
+    prefers_dark_scheme, pulse_declaration, pulse_gradient_declaration, quote_blocks, radius_for,
+    run_after, set_style_nonce, shimmer_declaration, style_injected, table_layout,
+    text_line_blocks, text_line_widths, text_lines_layout, trace_lifecycle, uses_static_styles,
+    visibility_change, watch_color_scheme, wave_declaration, wave_duration,
+};
+use leptos::either::Either;
+use leptos::html::Div;
+use leptos::prelude::*;
+use web_sys::js_sys;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::wasm_bindgen::prelude::*;
+use web_sys::{IntersectionObserver, IntersectionObserverEntry};
+
+/// An `IntersectionObserver` paired with the callback it invokes.
+type VisibilityObserver = (
+    IntersectionObserver,
+    Closure<dyn FnMut(js_sys::Array, IntersectionObserver)>,
+);
+
+/// Skeleton Component
+///
+/// A flexible and customizable skeleton loader for Leptos applications. It mirrors the
+/// `Skeleton` components of the Yew and Dioxus backends: the same variants, animations,
+/// themes, and visibility handling, rendered from the same shared style builders.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use skeleton_rs::leptos::Skeleton;
+/// use skeleton_rs::{Animation, Variant};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let (loaded, _set_loaded) = signal(false);
+///     view! {
+///         <Skeleton variant=Variant::Text animation=Animation::Wave width="200px" show=loaded>
+///             <p>"Loaded content"</p>
+///         </Skeleton>
+///     }
+/// }
+/// ```
+///
+/// # Notes
+/// - `show` accepts a signal: the skeleton is hidden and the children revealed as soon as it
+///   becomes `true`, and shown again (after `delay_ms`) when it becomes `false`.
+/// - With `animate_on_visible`, an `IntersectionObserver` shows the skeleton once it enters
+///   the viewport; the observer is disconnected when the component is cleaned up.
+#[component]
+pub fn Skeleton(
+    /// The visual variant of the skeleton.
+    ///
+    /// Defaults to `Variant::Text`.
+    #[prop(optional)]
+    variant: Variant,
+
+    /// Animation style applied to the skeleton.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s animation, or `Animation::Pulse`.
+    #[prop(optional)]
+    animation: Option<Animation>,
+
+    /// Direction of the wave animation and of its gradient.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s direction, or `Direction::LeftToRight`.
+    #[prop(optional)]
+    direction: Option<Direction>,
+
+    /// The theme of the skeleton appearance.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s theme, or `Theme::Light`.
+    #[prop(optional)]
+    theme: Option<Theme>,
+
+    /// How `Animation::Pulse` animates the skeleton.
+    ///
+    /// Defaults to `PulseMode::Opacity`.
+    #[prop(optional)]
+    pulse_mode: PulseMode,
+
+    /// Lowest opacity reached by the pulse animation, clamped to `0.0..=1.0`.
+    ///
+    /// Defaults to `0.4`.
+    #[prop(default = DEFAULT_PULSE_MIN_OPACITY)]
+    pulse_min_opacity: f32,
+
+    /// Optional timing curve overriding the default easing of the animation.
+    ///
+    /// Defaults to `None`.
+    #[prop(optional)]
+    easing: Option<Easing>,
+
+    /// Speed of the wave animation expressed in sweeps per second.
+    ///
+    /// Defaults to `None`, which keeps the default `1.6s` sweep.
+    #[prop(optional)]
+    sweeps_per_second: Option<f32>,
+
+    /// Optional duration of one animation cycle, as a CSS time (e.g. `"800ms"`).
+    ///
+    /// Defaults to `None`.
+    #[prop(optional)]
+    animation_duration: Option<&'static str>,
+
+    /// Optional delay before the animation starts, as a CSS time (e.g. `"200ms"`).
+    ///
+    /// Defaults to `None`.
+    #[prop(optional)]
+    animation_delay: Option<&'static str>,
+
+    /// The width of the skeleton.
+    ///
+    /// Defaults to `"100%"`.
+    #[prop(into, default = "100%".to_string())]
+    width: String,
+
+    /// The height of the skeleton.
+    ///
+    /// Defaults to `"1em"`.
+    #[prop(into, default = "1em".to_string())]
+    height: String,
+
+    /// Optional aspect ratio of the skeleton, e.g. `"16 / 9"`.
+    ///
+    /// Defaults to `None`.
+    #[prop(optional)]
+    aspect_ratio: Option<&'static str>,
+
+    /// Number of text lines rendered by a `Variant::Text` skeleton, or of body lines below
+    /// the heading of a `Variant::Paragraph`.
+    ///
+    /// Defaults to `1`.
+    #[prop(default = 1)]
+    lines: usize,
+
+    /// Gap between the lines of a multi-line text skeleton.
+    ///
+    /// Defaults to `"0.5em"`.
+    #[prop(default = "0.5em")]
+    line_gap: &'static str,
+
+    /// Optional height of the line box of each line of a multi-line text skeleton.
+    ///
+    /// Each line stays `height` tall and is centered in a box of this height, so a paragraph
+    /// with a known line height is mimicked exactly.
+    #[prop(optional)]
+    line_height_each: Option<&'static str>,
+
+    /// Optional width of the last line of a multi-line text skeleton.
+    ///
+    /// Defaults to `None`, which draws it at `60%`.
+    #[prop(optional)]
+    last_line_width: Option<&'static str>,
+
+    /// Number of quote lines in a `Variant::Quote` placeholder.
+    ///
+    /// Defaults to `3`.
+    #[prop(default = 3)]
+    quote_lines: usize,
+
+    /// Number of rows, header included, in a `Variant::Table` placeholder.
+    ///
+    /// Defaults to `5`.
+    #[prop(default = 5)]
+    rows: usize,
+
+    /// Number of columns in a `Variant::Table` placeholder.
+    ///
+    /// Defaults to `4`.
+    #[prop(default = 4)]
+    columns: usize,
+
+    /// The border radius of the skeleton.
+    ///
+    /// Defaults to `"4px"`.
+    #[prop(default = "4px")]
+    border_radius: &'static str,
+
+    /// The CSS `display` of the skeleton.
+    ///
+    /// Defaults to `"inline-block"`.
+    #[prop(default = "inline-block")]
+    display: &'static str,
+
+    /// The line height of the skeleton.
+    ///
+    /// Defaults to `"1"`.
+    #[prop(default = "1")]
+    line_height: &'static str,
+
+    /// The CSS `position` of the skeleton.
+    ///
+    /// Defaults to `"relative"`.
+    #[prop(default = "relative")]
+    position: &'static str,
+
+    /// The CSS `overflow` of the skeleton.
+    ///
+    /// Defaults to `"hidden"`.
+    #[prop(default = "hidden")]
+    overflow: &'static str,
+
+    /// The margin around the skeleton.
+    ///
+    /// Defaults to `""`.
+    #[prop(optional)]
+    margin: &'static str,
+
+    /// Additional inline styles appended to the skeleton.
+    ///
+    /// Accepts a string literal or a computed `String`. Defaults to `""`.
+    #[prop(optional, into)]
+    custom_style: String,
+
+    /// Optional maximum width of the skeleton.
+    #[prop(optional)]
+    max_width: Option<&'static str>,
+
+    /// Optional minimum width of the skeleton.
+    #[prop(optional)]
+    min_width: Option<&'static str>,
+
+    /// Optional maximum height of the skeleton.
+    #[prop(optional)]
+    max_height: Option<&'static str>,
+
+    /// Optional minimum height of the skeleton.
+    #[prop(optional)]
+    min_height: Option<&'static str>,
+
+    /// Whether the skeleton scales with its container, up to `width`.
+    ///
+    /// Defaults to `false`.
+    #[prop(optional)]
+    responsive: bool,
+
+    /// Whether the content is shown instead of the skeleton.
+    ///
+    /// Defaults to `false`.
+    #[prop(into, optional)]
+    show: Signal<bool>,
+
+    /// Delay, in milliseconds, before the skeleton is shown.
+    ///
+    /// Defaults to `0`.
+    #[prop(optional)]
+    delay_ms: u32,
+
+    /// How long, in milliseconds, `show` must stay `true` before the skeleton is hidden.
+    ///
+    /// Together with `delay_ms`, which only shows the skeleton once `show` has stayed `false`
+    /// for the whole delay, this coalesces rapid `show` toggles (e.g. on a flaky network)
+    /// instead of flickering. Defaults to `0`.
+    #[prop(optional)]
+    hide_debounce_ms: u32,
+
+    /// Minimum time, in milliseconds, the skeleton stays on screen once shown.
+    ///
+    /// When `show` becomes `true` shortly after the skeleton appeared, the hide is postponed
+    /// until it has been visible this long, so fast loads don't flash it for a single frame.
+    /// It only applies once the skeleton is shown: a skeleton mounted with a `delay_ms` starts
+    /// hidden, so data arriving within the delay still skips it entirely. Defaults to `0`.
+    #[prop(optional)]
+    min_display_ms: u32,
+
+    /// Controlled visibility of the skeleton, for parents that drive it directly.
+    ///
+    /// When set, the skeleton reflects the signal: it is shown exactly while the signal is
+    /// `true` and the children while it is `false`. `show`, `delay_ms`, `hide_debounce_ms`, and
+    /// `min_display_ms` are ignored, so the parent can reveal the content at any time, even
+    /// mid-delay. Defaults to `None` (uncontrolled, driven by `show`).
+    #[prop(into, optional)]
+    visible: Option<Signal<bool>>,
+
+    /// Whether the skeleton is shown once it scrolls into view.
+    ///
+    /// Defaults to `false`.
+    #[prop(optional)]
+    animate_on_visible: bool,
+
+    /// Called once the skeleton first scrolls into view, e.g. to start lazy loading.
+    ///
+    /// Fires at most once per mounted skeleton and requires `animate_on_visible`. Defaults
+    /// to `None`.
+    #[prop(optional, into)]
+    on_visible: Option<Callback<()>>,
+
+    /// Called whenever the skeleton appears or gives way to its children.
+    ///
+    /// Receives the new visibility of the skeleton: `true` once it is shown (e.g. after
+    /// `delay_ms` elapses) and `false` once it is hidden because `show` became `true`. Not
+    /// called for the initial state. Defaults to `None`.
+    #[prop(optional, into)]
+    on_reveal: Option<Callback<bool>>,
+
+    /// Whether the skeleton reacts to hover.
+    #[prop(optional)]
+    animate_on_hover: bool,
+
+    /// Whether the animation pauses while hovered.
+    #[prop(optional)]
+    pause_on_hover: bool,
+
+    /// Whether the skeleton shows a focus outline.
+    #[prop(optional)]
+    animate_on_focus: bool,
+
+    /// Whether the skeleton shrinks slightly while pressed.
+    #[prop(optional)]
+    animate_on_active: bool,
+
+    /// Whether the skeleton is hidden when printing.
+    #[prop(optional)]
+    hide_on_print: bool,
+
+    /// Whether the animation stops for users who prefer reduced motion.
+    ///
+    /// Defaults to `true`.
+    #[prop(default = true)]
+    respect_reduced_motion: bool,
+
+    /// Optional DOM id of the skeleton element.
+    ///
+    /// Defaults to `None`.
+    #[prop(optional)]
+    id: Option<&'static str>,
+
+    /// Optional accessible label announced while loading.
+    ///
+    /// Without one, the skeleton is hidden from assistive technology. Defaults to `None`.
+    #[prop(optional)]
+    loading_label: Option<&'static str>,
+
+    /// Whether the skeleton is marked `aria-busy="true"`.
+    ///
+    /// Like `loading_label`, this exposes the skeleton as a loading status. Defaults to `false`.
+    #[prop(optional)]
+    aria_busy: bool,
+
+    /// Content revealed once `show` becomes `true`.
+    #[prop(optional)]
+    children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let defaults = use_context::<SkeletonDefaults>().unwrap_or_default();
+    let theme = defaults.theme(theme.as_ref());
+    let animation = defaults.animation(animation.as_ref());
+    let variant_name = variant.as_str();
+    let animation_name = animation.as_str();
+    let theme_name = theme.as_str();
+    let direction = defaults.direction(direction.as_ref());
+    let instance = next_instance_id();
+    let controlled = visible;
+    let visible = RwSignal::new(controlled.map_or_else(
+        || mount_visible(show.get_untracked(), delay_ms),
+        |controlled| controlled.get_untracked(),
+    ));
+    let pending_timer = StoredValue::new_local(None::<Timer>);
+    let shown_at = StoredValue::new(now_ms());
+    Effect::new(move |_| {
+        if visible.get() {
+            shown_at.set_value(now_ms());
+        }
+    });
+    Effect::new(move |_| {
+        // A flip of `show` cancels the change still pending.
+        pending_timer.update_value(|timer| {
+            if let Some(timer) = timer.take() {
+                timer.cancel();
+            }
+        });
+        if let Some(controlled) = controlled {
+            visible.set(controlled.get());
+            return;
+        }
+        let shown_for_ms = visible
+            .get_untracked()
+            .then(|| now_ms() - shown_at.get_value());
+        let change = visibility_change(
+            show.get(),
+            shown_for_ms,
+            delay_ms,
+            min_display_ms,
+            hide_debounce_ms,
+        );
+        let hide = move || {
+            visible.set(false);
+            trace_lifecycle!(instance, id, "revealed");
+        };
+        match change {
+            (0, false) => hide(),
+            (wait_ms, false) => pending_timer.set_value(Some(run_after(wait_ms, hide))),
+            (0, true) => visible.set(true),
+            (delay_ms, true) => {
+                trace_lifecycle!(instance, id, delay_ms, "delay started");
+                let timer = run_after(delay_ms, move || {
+                    trace_lifecycle!(instance, id, delay_ms, "delay elapsed");
+                    visible.set(true);
+                });
+                pending_timer.set_value(Some(timer));
+            }
+        }
+    });
+
+    Effect::new(move |previous: Option<bool>| {
+        let visible = visible.get();
+        if previous.is_some_and(|previous| previous != visible) {
+            if let Some(on_reveal) = on_reveal {
+                on_reveal.run(visible);
+            }
+        }
+        visible
+    });
+
+    let node_ref = NodeRef::<Div>::new();
+    let intersection_observer = StoredValue::new_local(None::<VisibilityObserver>);
+    let notified_visible = StoredValue::new(false);
+    Effect::new(move |_| {
+        let Some(element) = node_ref.get().filter(|_| animate_on_visible) else {
+            return;
+        };
+        let cb = Closure::wrap(Box::new(
+            move |entries: js_sys::Array, _observer: IntersectionObserver| {
+                let intersecting = entries.iter().any(|entry| {
+                    entry
+                        .unchecked_into::<IntersectionObserverEntry>()
+                        .is_intersecting()
+                });
+                if intersecting {
+                    trace_lifecycle!(instance, id, "intersecting");
+                    visible.set(true);
+                    if !notified_visible.get_value() {
+                        notified_visible.set_value(true);
+                        if let Some(on_visible) = on_visible {
+                            on_visible.run(());
+                        }
+                    }
+                }
+            },
+        )
+            as Box<dyn FnMut(js_sys::Array, IntersectionObserver)>);
+        if let Ok(observer) = IntersectionObserver::new(cb.as_ref().unchecked_ref()) {
+            observer.observe(&element);
+            intersection_observer.update_value(|slot| {
+                if let Some((previous, _closure)) = slot.replace((observer, cb)) {
+                    previous.disconnect();
+                }
+            });
+        }
+    });
+    on_cleanup(move || {
+        intersection_observer.try_update_value(|slot| {
+            if let Some((observer, _closure)) = slot.take() {
+                observer.disconnect();
+            }
+        });
+        pending_timer.try_update_value(|timer| {
+            if let Some(timer) = timer.take() {
+                timer.cancel();
+            }
+        });
+    });
+
+    // Only `Theme::System` follows the OS preference, so no other theme queries it.
+    let system_theme = theme == Theme::System;
+    let prefers_dark = RwSignal::new(system_theme && prefers_dark_scheme());
+    let color_scheme_listener = StoredValue::new_local(None::<ColorSchemeListener>);
+    // Effects only run in the browser, so server rendering never installs the listener.
+    Effect::new(move |_| {
+        if system_theme {
+            color_scheme_listener.set_value(watch_color_scheme(move |dark| prefers_dark.set(dark)));
+        }
+    });
+    on_cleanup(move || {
+        color_scheme_listener.try_update_value(Option::take);
+    });
+
+    let effective_radius = radius_for(variant, border_radius);
+    let text_lines = (variant == Variant::Text)
+        .then_some(lines)
+        .filter(|lines| *lines > 1);
+    let wave_keyframes = direction.wave_keyframes();
+    let wave_keyframes_name = direction.wave_keyframes_name();
+    let angle = direction.gradient_angle();
+
+    let delay = animation_delay.map_or_else(String::new, |delay| format!(" {delay}"));
+    let pulse_timing = format!(
+        "{} {}{delay}",
+        animation_duration.unwrap_or("1.5s"),
+        easing.map_or("ease-in-out", Easing::timing_function)
+    );
+    let wave_timing = format!(
+        "{} {}{delay}",
+        animation_duration.map_or_else(|| wave_duration(sweeps_per_second), str::to_string),
+        easing.map_or("linear", Easing::timing_function)
+    );
+    let base_animation = match animation {
+        Animation::Pulse => pulse_declaration(pulse_mode.keyframes_name(), &pulse_timing),
+        Animation::Wave => wave_declaration(&direction, &wave_timing),
+        Animation::PulseGradient => pulse_gradient_declaration(angle, &pulse_timing),
+        Animation::Shimmer => shimmer_declaration(&direction, &wave_timing),
+        Animation::Glow => glow_declaration(&pulse_timing),
+        Animation::None => String::new(),
+    };
+
+    let anim_class = (!base_animation.is_empty()).then(|| animation_class(&base_animation));
+    // Composite placeholders animate their blocks instead of the transparent container.
+    let (table_layout, table_cells) = (variant == Variant::Table)
+        .then(|| table_layout(rows, columns, effective_radius))
+        .unzip();
+    let blocks = match variant {
+        Variant::Table => table_cells,
+        Variant::Quote => Some(quote_blocks(quote_lines)),
+        Variant::Card => Some(card_blocks(effective_radius)),
+        Variant::Paragraph => Some(paragraph_blocks(
+            lines,
+            &height,
+            line_height_each,
+            last_line_width.unwrap_or("60%"),
+            effective_radius,
+        )),
+        _ => text_lines.map(|lines| {
+            text_line_blocks(
+                lines,
+                &height,
+                line_height_each,
+                last_line_width.unwrap_or("60%"),
+                effective_radius,
+            )
+        }),
+    };
+    let block_class = blocks.as_ref().and(anim_class.clone());
+    let container_anim_class = anim_class.clone().filter(|_| blocks.is_none());
+    // Without runtime styles, a generated class has no rule and its animation is inlined.
+    let inline_anim = inline_animation(anim_class.as_deref(), &base_animation);
+    let (block_inline_anim, container_inline_anim) = if blocks.is_some() {
+        (inline_anim, String::new())
+    } else {
+        (String::new(), inline_anim)
+    };
+
+    // Re-derived when the OS color scheme changes, so `Theme::System` updates in place.
+    let styles = Memo::new(move |_| {
+        let background_color = theme.scheme_color(prefers_dark.get());
+        let style_params = StyleParams {
+            variant,
+            background_color: &background_color,
+            highlight_color: theme.highlight(),
+            pulse_min_opacity,
+            border_radius: effective_radius,
+            width: &width,
+            height: &height,
+            aspect_ratio,
+            responsive,
+            display,
+            position,
+            overflow,
+            margin,
+            line_height,
+            max_width,
+            min_width,
+            max_height,
+            min_height,
+            multi_line: text_lines.is_some(),
+            template_layout: table_layout.as_deref(),
+            line_gap,
+            custom_style: &custom_style,
+            animate_on_hover,
+            pause_on_hover,
+            respect_reduced_motion,
+            animate_on_focus,
+            animate_on_active,
+            hide_on_print,
+            ..StyleParams::default()
+        };
+        (
+            format!(
+                "{}{container_inline_anim}",
+                build_skeleton_style(&style_params)
+            ),
+            build_class_names(&style_params, container_anim_class.as_deref()),
+        )
+    });
+
+    Effect::new(move |_| {
+        if let Some(anim_class) = anim_class
+            .as_ref()
+            .filter(|class| !is_preset_animation_class(class))
+        {
+            inject_style(anim_class, &format!(".{anim_class} {{ {base_animation} }}"));
+        }
+        if uses_static_styles(None) {
+            return;
+        }
+        if !style_injected(None, "skeleton-rs-style") {
+            inject_style("skeleton-rs-style", &base_stylesheet());
+        }
+        // Each direction has its own keyframes, so waves of several directions can coexist.
+        if animation == Animation::Wave {
+            inject_style(&wave_keyframes_name, &wave_keyframes);
+        }
+    });
+
+    let decorative = is_decorative(loading_label, aria_busy, None);
+    let role = if decorative { "presentation" } else { "status" };
+    let aria_hidden = decorative.then_some("true");
+    let aria_busy = aria_busy.then_some("true");
+    let aria_live = aria_live(Some(role));
+
+    move || {
+        if visible.get() {
+            let blocks = blocks.clone().map(|blocks| {
+                blocks
+                    .into_iter()
+                    .map(|block| {
+                        let style = format!("{block}{block_inline_anim}");
+                        view! { <span class=block_class.clone() style=style></span> }
+                    })
+                    .collect_view()
+            });
+            Either::Left(view! {
+                <div
+                    node_ref=node_ref
+                    id=id
+                    class=move || styles.with(|(_, class_names)| class_names.clone())
+                    style=move || styles.with(|(style, _)| style.clone())
+                    role=role
+                    aria-hidden=aria_hidden
+                    aria-busy=aria_busy
+                    aria-live=aria_live
+                    aria-label=loading_label
+                    data-variant=variant_name
+                    data-animation=animation_name
+                    data-theme=theme_name
+                >
+                    {blocks}
+                </div>
+            })
+        } else {
+            Either::Right(children.as_ref().map(|children| children()))
+        }
+    }
+}
+
+/// Builds props from a `SkeletonConfig`, e.g. to render a skeleton from data with
+/// `Skeleton(props)`.
+impl From<SkeletonConfig> for SkeletonProps {
+    fn from(config: SkeletonConfig) -> Self {
+        let mut props = SkeletonProps::builder().build();
+        props.variant = config.variant;
+        props.animation = config.animation;
+        props.direction = config.direction;
+        props.theme = config.theme;
+        props.width = config.width;
+        props.height = config.height;
+        props.border_radius = config.border_radius;
+        props.lines = config.lines;
+        props.delay_ms = config.delay_ms;
+        props.animate_on_visible = config.animate_on_visible;
+        props.custom_style = config.custom_style;
+        props
+    }
+}
+
+/// Skeleton Group Component
+///
+/// Wraps several skeletons in a single container, optionally laid out as a flex row or
+/// column spaced by `gap`.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use skeleton_rs::leptos::{Skeleton, SkeletonGroup};
+/// use skeleton_rs::{GroupDirection, Variant};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <SkeletonGroup direction=GroupDirection::Row gap="1rem">
+///             <Skeleton variant=Variant::Rounded width="100px" height="100px" />
+///             <Skeleton variant=Variant::Rounded width="100px" height="100px" />
+///         </SkeletonGroup>
+///     }
+/// }
+/// ```
+#[component]
+pub fn SkeletonGroup(
+    /// The grouped skeletons.
+    children: Children,
+
+    /// Inline styles appended to the group container.
+    #[prop(optional)]
+    style: &'static str,
+
+    /// CSS classes applied to the group container.
+    #[prop(optional)]
+    class: &'static str,
+
+    /// Optional amount by which consecutive children overlap, e.g. `"12px"`.
+    #[prop(optional)]
+    overlap: Option<&'static str>,
+
+    /// Whether `overlap` uses logical margins.
+    ///
+    /// Defaults to `false`.
+    #[prop(optional)]
+    logical_props: bool,
+
+    /// Optional pair of base colors alternated between odd and even children.
+    #[prop(optional)]
+    stripe_colors: Option<(&'static str, &'static str)>,
+
+    /// Space between children, as a CSS length or a spacing token, e.g. `Spacing::Md`.
+    ///
+    /// Applies when `direction` is set. Defaults to `"0.5rem"`.
+    #[prop(into, default = "0.5rem")]
+    gap: &'static str,
+
+    /// Optional main axis of a flexbox layout, e.g. `GroupDirection::Column`.
+    ///
+    /// Defaults to `None`.
+    #[prop(optional)]
+    direction: Option<GroupDirection>,
+) -> impl IntoView {
+    let mut class_names = class.to_string();
+    let mut group_style = String::new();
+    if let Some(overlap) = overlap {
+        class_names.push_str(" skeleton-group-overlap");
+        if logical_props {
+            class_names.push_str(" skeleton-group-logical");
+        }
+        group_style.push_str(&format!("--skeleton-group-overlap: {overlap};"));
+    }
+    if let Some((odd, even)) = stripe_colors {
+        class_names.push_str(" skeleton-group-striped");
+        group_style.push_str(&format!(
+            "--skeleton-group-stripe-odd: {odd}; --skeleton-group-stripe-even: {even};"
+        ));
+    }
+    if let Some(direction) = direction {
+        group_style.push_str(&format!(
+            "display: flex; flex-direction: {}; gap: {gap};",
+            direction.css_value()
+        ));
+    }
+    group_style.push_str(style);
+
+    view! {
+        <div class=class_names style=group_style>
+            {children()}
+        </div>
+    }
+}
+
+/// Skeleton Text Component
+///
+/// Paragraph placeholder whose lines vary in width like real text. Every line is a `Text`
+/// skeleton whose width comes from `text_line_widths`, so the paragraph looks natural while
+/// rendering identically on the server and the client.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use skeleton_rs::leptos::SkeletonText;
+///
+/// #[component]
+/// pub fn Article() -> impl IntoView {
+///     view! {
+///         <SkeletonText lines=4 seed=1 />
+///         <SkeletonText lines=2 widths=vec!["100%".to_string(), "30%".to_string()] />
+///     }
+/// }
+/// ```
+#[component]
+pub fn SkeletonText(
+    /// Number of lines in the paragraph.
+    ///
+    /// Defaults to `3`.
+    #[prop(default = 3)]
+    lines: usize,
+
+    /// Seed of the line widths; the same seed always renders the same widths.
+    ///
+    /// Defaults to `0`.
+    #[prop(optional)]
+    seed: u64,
+
+    /// Explicit width of each line, overriding the generated ones.
+    ///
+    /// Lines without an entry keep their generated width.
+    #[prop(optional)]
+    widths: Option<Vec<String>>,
+
+    /// Height of each line.
+    ///
+    /// Defaults to `"1em"`.
+    #[prop(default = "1em")]
+    height: &'static str,
+
+    /// Gap between the lines.
+    ///
+    /// Defaults to `"0.5em"`.
+    #[prop(default = "0.5em")]
+    line_gap: &'static str,
+
+    /// The theme of the lines.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s theme, or `Theme::Light`.
+    #[prop(optional)]
+    theme: Option<Theme>,
+
+    /// The animation of the lines.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s animation, or `Animation::Pulse`.
+    #[prop(optional)]
+    animation: Option<Animation>,
+
+    /// Inline styles appended to the paragraph container.
+    #[prop(optional)]
+    style: &'static str,
+
+    /// CSS classes applied to the paragraph container.
+    #[prop(optional)]
+    class: &'static str,
+) -> impl IntoView {
+    let defaults = use_context::<SkeletonDefaults>().unwrap_or_default();
+    let theme = defaults.theme(theme.as_ref());
+    let animation = defaults.animation(animation.as_ref());
+    let widths = text_line_widths(lines, seed)
+        .into_iter()
+        .enumerate()
+        .map(|(line, width)| {
+            widths
+                .as_ref()
+                .and_then(|widths| widths.get(line).cloned())
+                .unwrap_or(width)
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <div class=class style=format!("{}{style}", text_lines_layout(line_gap))>
+            {widths
+                .into_iter()
+                .map(|width| {
+                    view! {
+                        <Skeleton
+                            variant=Variant::Text
+                            width=width
+                            height=height
+                            display="block"
+                            theme=theme.clone()
+                            animation=animation.clone()
+                        />
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
+
+/// Skeleton Provider Component
+///
+/// Supplies app-wide defaults to every skeleton rendered inside it. Skeletons resolve
+/// `theme`, `animation`, and `direction` with the precedence explicit prop > provider > enum
+/// default, and nested providers inherit what they do not set from the enclosing one.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use skeleton_rs::leptos::{Skeleton, SkeletonProvider};
+/// use skeleton_rs::{Animation, Theme};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <SkeletonProvider theme=Theme::Dark animation=Animation::Wave>
+///             <Skeleton width="200px" />
+///             <Skeleton width="120px" theme=Theme::Light />
+///         </SkeletonProvider>
+///     }
+/// }
+/// ```
+#[component]
+pub fn SkeletonProvider(
+    /// Theme of the skeletons inside the provider that do not set `theme`.
+    #[prop(optional)]
+    theme: Option<Theme>,
+
+    /// Animation of the skeletons inside the provider that do not set `animation`.
+    #[prop(optional)]
+    animation: Option<Animation>,
+
+    /// Direction of the skeletons inside the provider that do not set `direction`.
+    #[prop(optional)]
+    direction: Option<Direction>,
+
+    /// CSP nonce set as the `nonce` attribute of every `<style>` element the skeletons inject,
+    /// for pages served with a `style-src 'nonce-...'` Content-Security-Policy.
+    #[prop(optional, into)]
+    nonce: Option<String>,
+
+    /// The skeletons receiving the defaults.
+    children: Children,
+) -> impl IntoView {
+    // Set before the children are created, so they inject their styles with it.
+    if let Some(nonce) = &nonce {
+        set_style_nonce(nonce);
+    }
+    let outer = use_context::<SkeletonDefaults>().unwrap_or_default();
+    provide_context(
+        SkeletonDefaults {
+            theme,
+            animation,
+            direction,
+        }
+        .inherit(&outer),
+    );
+    children()
+}
@@ -16,4 +16,9 @@ pub mod dioxus;
 #[cfg(feature = "lep")]
 pub mod leptos;
 
-pub use common::{Animation, Direction, Theme, Variant};
+pub use common::{
+    Animation, AttachedSkeleton, CssColor, CssLength, Direction, Easing, FocusOutline,
+    GroupDirection, HoverFilter, KeyframeStep, MirrorBox, PulseMode, SkeletonConfig,
+    SkeletonDefaults, Spacing, SpacingScale, Theme, Variant, register_palette,
+    register_spacing_scale, remove_styles, skeleton_css, skeleton_styles,
+};
@@ -1,12 +1,31 @@
 #![doc = include_str!("../YEW.md")]
 
-use crate::common::{Animation, Direction, Theme, Variant};
-use gloo_timers::callback::Timeout;
+use crate::common::{
+    Animation, AttachedSkeleton, DEFAULT_PULSE_MIN_OPACITY, Direction, Easing, FocusOutline,
+    GLOW_KEYFRAMES, GroupDirection, HoverFilter, INFER_SIZE_PROBE_STYLE, KeyframeStep,
+    LIST_ITEM_LAYOUT, LIST_ITEM_TEXT_STYLE, LOW_POWER_SLOWDOWN, MirrorBox, NOSCRIPT_STYLE_ID,
+    OVERLAY_HIGHLIGHT, PULSE_DURATION_MS, PULSE_KEYFRAMES, PulseMode, REVALIDATING_FILL_STYLE,
+    REVALIDATING_OVERLAY_STYLE, RevealScheduler, SHIMMER_KEYFRAMES, SR_ONLY_STYLE, SkeletonConfig,
+    SkeletonDefaults, Spacing, StyleParams, Theme, Timer, Variant, WaveImpl, animation_class,
+    aria_live, auto_contrast_color, build_class_names, build_skeleton_style, card_blocks,
+    clamped_lines, compile_keyframes, css_time_ms, global_sync_delay, glow_declaration,
+    inject_animation_class, inject_base_stylesheet, inject_style, inject_style_in,
+    inline_animation, is_decorative, low_power_style, measure_mirror, mirror_box_style,
+    mount_visible, next_instance_id, next_unique_id, noscript_pulse_css, noscript_shared_css,
+    now_ms, paragraph_blocks, parse_template, prefers_dark_scheme, pulse_declaration,
+    pulse_gradient_declaration, quote_blocks, radius_for, run_after, runtime_styles_enabled,
+    scoped_animation_css, set_style_nonce, shimmer_declaration, table_layout, template_layout,
+    text_line_blocks, text_line_widths, text_lines_layout, trace_lifecycle, uses_static_styles,
+    visibility_change, watch_color_scheme, wave_declaration, wave_duration, wave_duration_ms,
+};
+use std::borrow::Cow;
 use web_sys::js_sys;
 use web_sys::wasm_bindgen::JsCast;
 use web_sys::wasm_bindgen::prelude::*;
-use web_sys::window;
-use web_sys::{HtmlElement, IntersectionObserver, IntersectionObserverEntry};
+use web_sys::{
+    Element, HtmlElement, IntersectionObserver, IntersectionObserverEntry, ResizeObserver,
+};
+use yew::html::IntoPropValue;
 use yew::prelude::*;
 
 /// Properties for the `Skeleton` component.
@@ -28,32 +47,230 @@ pub struct SkeletonProps {
     /// Animation style applied to the skeleton.
     ///
     /// Controls how the skeleton animates, e.g., pulse, wave, etc.
-    /// Defaults to `Animation::Pulse`.
+    /// Defaults to the enclosing `SkeletonProvider`'s animation, or `Animation::Pulse`.
     #[prop_or_default]
-    pub animation: Animation,
+    pub animation: Option<Animation>,
+
+    /// Whether the animation stops for users who prefer reduced motion.
+    ///
+    /// When `true`, the skeleton stays static while the `prefers-reduced-motion: reduce`
+    /// media query matches. Defaults to `true`.
+    #[prop_or(true)]
+    pub respect_reduced_motion: bool,
+
+    /// What `Animation::Pulse` varies.
+    ///
+    /// `PulseMode::Color` animates the background color instead of the opacity, so the
+    /// skeleton stays opaque over images or patterned backgrounds. Defaults to
+    /// `PulseMode::Opacity`.
+    #[prop_or_default]
+    pub pulse_mode: PulseMode,
+
+    /// Lowest opacity reached by the pulse animation, between `0.0` and `1.0`.
+    ///
+    /// Lower values pulse more strongly; values outside the range are clamped. Also applies
+    /// to `Animation::PulseGradient`. Defaults to `0.4`.
+    #[prop_or(DEFAULT_PULSE_MIN_OPACITY)]
+    pub pulse_min_opacity: f32,
+
+    /// Optional timing function preset of the animation, e.g. `Easing::Spring`.
+    ///
+    /// Defaults to `None`, which keeps each animation's own timing: `ease-in-out` for
+    /// pulses and `linear` for waves.
+    #[prop_or(None)]
+    pub easing: Option<Easing>,
+
+    /// Optional custom keyframes as `(percent, step)` pairs.
+    ///
+    /// The stops are compiled into a uniquely named `@keyframes` rule that replaces
+    /// `animation`, for full control without writing raw CSS. Defaults to `None`.
+    #[prop_or(None)]
+    pub keyframes: Option<Vec<(u8, KeyframeStep)>>,
 
     /// Direction of the animation direction and background color gradient.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s direction, or `Direction::LeftToRight`.
+    #[prop_or_default]
+    pub direction: Option<Direction>,
+
+    /// How the wave animation is rendered.
+    ///
+    /// `WaveImpl::Transform` slides an overlaid gradient with `translate`, which is usually
+    /// smoother on low-end devices than animating `background-position`.
+    /// Defaults to `WaveImpl::BackgroundPosition`.
     #[prop_or_default]
-    pub direction: Direction,
+    pub wave_impl: WaveImpl,
+
+    /// Speed of the wave animation expressed in sweeps per second.
+    ///
+    /// Converted into the wave's `animation-duration` (e.g. `2.0` yields `0.5s`).
+    /// Defaults to `None`, which keeps the default `1.6s` sweep.
+    #[prop_or(None)]
+    pub sweeps_per_second: Option<f32>,
+
+    /// Optional duration of one animation cycle, as a CSS time (e.g. `"800ms"`).
+    ///
+    /// Overrides the default `1.5s` pulse and the wave duration, including the one derived
+    /// from `sweeps_per_second`. Defaults to `None`.
+    #[prop_or(None)]
+    pub animation_duration: Option<&'static str>,
+
+    /// Optional delay before the animation starts, as a CSS time (e.g. `"200ms"`).
+    ///
+    /// Useful to stagger the animations of neighboring skeletons. Defaults to `None`.
+    #[prop_or(None)]
+    pub animation_delay: Option<&'static str>,
+
+    /// Optional offset added to the wave gradient's `background-position`, e.g. `"-50%"`.
+    ///
+    /// Shifts the phase of the shimmer along the sweep direction, so adjacent skeletons can
+    /// be staggered precisely. A lower-level complement to `sync_global`. Defaults to `None`.
+    #[prop_or(None)]
+    pub wave_offset: Option<&'static str>,
+
+    /// Whether the animation is aligned to a page-wide timeline.
+    ///
+    /// When `true`, a negative `animation-delay` puts newly mounted skeletons in phase with
+    /// every other synced skeleton, avoiding a random-phase look. Defaults to `false`.
+    #[prop_or(false)]
+    pub sync_global: bool,
+
+    /// Whether the animation is throttled to save battery.
+    ///
+    /// Doubles the cycle length and plays it in a few discrete steps instead of a smooth
+    /// curve, cutting repaints. Defaults to `false`.
+    #[prop_or(false)]
+    pub low_power: bool,
 
     /// The theme of the skeleton appearance.
     ///
     /// Allows switching between light or dark themes.
-    /// Defaults to `Theme::Light`.
+    /// Defaults to the enclosing `SkeletonProvider`'s theme, or `Theme::Light`.
     #[prop_or_default]
-    pub theme: Theme,
+    pub theme: Option<Theme>,
+
+    /// Whether the base color adapts to the background behind the skeleton.
+    ///
+    /// When `true`, the nearest non-transparent ancestor background is sampled after mount
+    /// and a contrasting base color replaces the theme's. Defaults to `false`.
+    #[prop_or(false)]
+    pub auto_contrast: bool,
 
     /// The width of the skeleton.
     ///
-    /// Accepts any valid CSS width value (e.g., `100%`, `200px`, `10rem`). Defaults to `"100%"`.
-    #[prop_or("100%")]
-    pub width: &'static str,
+    /// Accepts any valid CSS width value (e.g., `100%`, `200px`, `10rem`), either as a string
+    /// literal or a computed `String`. Defaults to `"100%"`.
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use skeleton_rs::yew::Skeleton;
+    ///
+    /// #[function_component(Widths)]
+    /// fn widths() -> Html {
+    ///     let owned = String::from("12rem");
+    ///     let columns = 4;
+    ///     html! {
+    ///         <>
+    ///             <Skeleton width="200px" />
+    ///             <Skeleton width={owned} />
+    ///             <Skeleton width={format!("calc(100% / {columns})")} />
+    ///         </>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::Static("100%"))]
+    pub width: AttrValue,
 
     /// The height of the skeleton.
     ///
-    /// Accepts any valid CSS height value. Defaults to `"1em"`.
-    #[prop_or("1em")]
-    pub height: &'static str,
+    /// Accepts any valid CSS height value, either as a string literal or a computed `String`.
+    /// Defaults to `"1em"`.
+    #[prop_or(AttrValue::Static("1em"))]
+    pub height: AttrValue,
+
+    /// Optional CSS aspect ratio of the skeleton, e.g. `"16 / 9"`.
+    ///
+    /// Unless `height` is set explicitly, no height is emitted and the browser derives it
+    /// from the width, so the skeleton fills its container and reserves the right box.
+    /// Defaults to `None`.
+    #[prop_or(None)]
+    pub aspect_ratio: Option<&'static str>,
+
+    /// Whether a zero or empty `width`/`height` falls back to a minimum visible size.
+    ///
+    /// Zero-sized skeletons are invisible; debug builds always log a warning for them.
+    /// Defaults to `false`.
+    #[prop_or(false)]
+    pub zero_size_fallback: bool,
+
+    /// Optional size of an icon placeholder.
+    ///
+    /// Only used with `Variant::Icon`, where it sets both width and height.
+    /// Defaults to `None`, which sizes the icon like a line of text (`1em`).
+    #[prop_or(None)]
+    pub icon_size: Option<&'static str>,
+
+    /// Thickness of a divider line.
+    ///
+    /// Only used with `Variant::Divider`, which renders a full-width line of this height.
+    /// Defaults to `"1px"`.
+    #[prop_or("1px")]
+    pub thickness: &'static str,
+
+    /// Number of quote lines in a testimonial placeholder.
+    ///
+    /// Only used with `Variant::Quote`. Defaults to `3`.
+    #[prop_or(3)]
+    pub quote_lines: usize,
+
+    /// Number of rows, header included, in a table placeholder.
+    ///
+    /// Only used with `Variant::Table`. Defaults to `5`.
+    #[prop_or(5)]
+    pub rows: usize,
+
+    /// Number of columns in a table placeholder.
+    ///
+    /// Only used with `Variant::Table`. Defaults to `4`.
+    #[prop_or(4)]
+    pub columns: usize,
+
+    /// Number of stacked text bars rendered by a `Variant::Text` skeleton.
+    ///
+    /// Values above `1` render a paragraph placeholder of that many bars, each `height`
+    /// tall, capped by `max_lines` when set. Also the number of body lines below the heading
+    /// of a `Variant::Paragraph`. Defaults to `1`.
+    #[prop_or(1)]
+    pub lines: usize,
+
+    /// Gap between the bars of a multi-line text skeleton.
+    ///
+    /// Defaults to `"0.5em"`.
+    #[prop_or("0.5em")]
+    pub line_gap: &'static str,
+
+    /// Optional height of the line box of each bar of a multi-line text skeleton.
+    ///
+    /// Each bar stays `height` tall and is centered in a box of this height, so a paragraph
+    /// with a known line height (e.g. `"1.5em"`) is mimicked exactly; set `line_gap` to `"0"`
+    /// when the line boxes already provide the spacing. Defaults to `None`.
+    #[prop_or(None)]
+    pub line_height_each: Option<&'static str>,
+
+    /// Optional width of the last bar of a multi-line text skeleton.
+    ///
+    /// Defaults to `None`, which renders the last bar at `60%` like the end of a paragraph.
+    #[prop_or(None)]
+    pub last_line_width: Option<&'static str>,
+
+    /// Optional layout template rendered as a composite placeholder, e.g.
+    /// `"avatar | line(80%) line(60%)"`.
+    ///
+    /// `|` separates columns laid out side by side; within a column, whitespace-separated
+    /// shapes (`avatar`, `avatar(size)`, `line`, `line(width)`, `block(width, height)`,
+    /// `button`) are stacked. Takes precedence over `variant`. Defaults to `None`.
+    #[prop_or(None)]
+    pub template: Option<&'static str>,
 
     /// Optional font size for the skeleton text.
     ///
@@ -61,6 +278,14 @@ pub struct SkeletonProps {
     #[prop_or(None)]
     pub font_size: Option<&'static str>,
 
+    /// Optional root font size in pixels, used as the basis for computed `em` sizes.
+    ///
+    /// Sizes the crate derives itself (the default icon size, the zero-size fallback) are
+    /// resolved against this value instead of the element's font. Set it in apps that change
+    /// the root font size. Defaults to `None`, which keeps them in `em`.
+    #[prop_or(None)]
+    pub root_font_size: Option<f32>,
+
     /// Border radius for the skeleton.
     ///
     /// Controls the rounding of the skeleton's corners. Accepts any valid CSS radius.
@@ -68,6 +293,39 @@ pub struct SkeletonProps {
     #[prop_or("4px")]
     pub border_radius: &'static str,
 
+    /// Optional border radius applied only to the top corners.
+    ///
+    /// Overrides the top-left and top-right radius, e.g. for image skeletons sitting atop a card body.
+    #[prop_or(None)]
+    pub border_radius_top: Option<&'static str>,
+
+    /// Optional border radius applied only to the bottom corners.
+    ///
+    /// Overrides the bottom-left and bottom-right radius.
+    #[prop_or(None)]
+    pub border_radius_bottom: Option<&'static str>,
+
+    /// Whether shape and size changes are animated.
+    ///
+    /// When `true`, changing `variant`, `width`, `height`, or the border radius transitions
+    /// smoothly instead of jumping to the new shape. Defaults to `false`.
+    #[prop_or(false)]
+    pub morph: bool,
+
+    /// Whether only a themed outline is rendered, without a background fill.
+    ///
+    /// The outline pulses with `Animation::Pulse`; with `Animation::Wave` a subtle sheen in
+    /// the theme color sweeps across the transparent interior. Defaults to `false`.
+    #[prop_or(false)]
+    pub outline_only: bool,
+
+    /// Whether the skeleton is drawn with a subtle inner shadow.
+    ///
+    /// The shadow is a darker shade of the theme color, so the placeholder reads as a recessed
+    /// content area rather than an empty box. Defaults to `false`.
+    #[prop_or(false)]
+    pub inset_shadow: bool,
+
     /// Display property for the skeleton.
     ///
     /// Determines the skeleton's display type (e.g., `inline-block`, `block`). Defaults to `"inline-block"`.
@@ -92,27 +350,118 @@ pub struct SkeletonProps {
     #[prop_or("hidden")]
     pub overflow: &'static str,
 
+    /// Optional number of grid columns spanned by the skeleton.
+    ///
+    /// Emits `grid-column: span N;`, so skeleton items fit into an existing CSS grid layout
+    /// such as a responsive card grid. Defaults to `None`.
+    #[prop_or(None)]
+    pub grid_span: Option<usize>,
+
     /// Margin applied to the skeleton.
     ///
     /// Accepts any valid CSS margin value. Defaults to `""`.
     #[prop_or_default]
     pub margin: &'static str,
 
+    /// Whether generated margins use logical properties.
+    ///
+    /// When `true`, `margin` is emitted as `margin-block`/`margin-inline`, so its left and right
+    /// sides flip in right-to-left layouts. Defaults to `false`.
+    #[prop_or(false)]
+    pub logical_props: bool,
+
     /// Additional inline styles.
     ///
-    /// Allows you to append arbitrary CSS to the skeleton component. Useful for quick overrides.
+    /// Allows you to append arbitrary CSS to the skeleton component, either as a string literal
+    /// or a computed `String`. Useful for quick overrides.
     #[prop_or_default]
-    pub custom_style: &'static str,
+    pub custom_style: AttrValue,
+
+    /// Optional id of a custom element whose shadow root receives the skeleton stylesheet.
+    ///
+    /// Set it when the skeleton renders inside a web component's open shadow root, which
+    /// document-level styles do not reach. Defaults to `None`.
+    #[prop_or(None)]
+    pub shadow_host: Option<&'static str>,
+
+    /// Whether the animation is emitted in a `<style>` element scoped to this skeleton.
+    ///
+    /// The keyframes are renamed uniquely for the instance and rendered inside the skeleton
+    /// instead of the global stylesheet, fully isolating embedded widgets; the style is
+    /// removed together with the skeleton. Defaults to `false`.
+    #[prop_or(false)]
+    pub scoped_styles: bool,
+
+    /// HTML tag of the skeleton element.
+    ///
+    /// With `"button"`, the placeholder is rendered as a disabled `<button>` (with
+    /// `aria-disabled`), matching the semantics of the button it stands in for.
+    /// Defaults to `"div"`.
+    #[prop_or("div")]
+    pub tag: &'static str,
+
+    /// Optional DOM `id` of the skeleton element.
+    ///
+    /// Lets other elements reference the loading region, e.g. via `aria-controls`.
+    #[prop_or(None)]
+    pub id: Option<&'static str>,
+
+    /// Optional id of the element describing the loading region.
+    ///
+    /// Rendered as `aria-describedby`. Point the revealed content at the same id so assistive
+    /// technology relates the content to the region it replaced.
+    #[prop_or(None)]
+    pub aria_describedby: Option<&'static str>,
+
+    /// Optional accessible label announcing the loading state, e.g. `"Loading orders"`.
+    ///
+    /// Rendered as `aria-label`. Setting it exposes the skeleton to assistive technology
+    /// instead of hiding it as decoration. Defaults to `None`.
+    #[prop_or(None)]
+    pub loading_label: Option<&'static str>,
+
+    /// Optional text exposed only to assistive technology, e.g.
+    /// `"Loading your 3 most recent orders"`.
+    ///
+    /// Rendered inside the skeleton as a visually hidden element, and marks the skeleton
+    /// `aria-busy="true"`. Defaults to `None`.
+    #[prop_or(None)]
+    pub sr_only_text: Option<&'static str>,
+
+    /// Whether the skeleton is marked `aria-busy="true"`.
+    ///
+    /// Like `loading_label`, this exposes the skeleton as a loading status. Defaults to `false`.
+    #[prop_or(false)]
+    pub aria_busy: bool,
+
+    /// Optional ARIA role of the skeleton element.
+    ///
+    /// Defaults to `None`, which renders `role="presentation"` for decorative skeletons and
+    /// `role="status"` once `loading_label` or `aria_busy` is set.
+    #[prop_or(None)]
+    pub role: Option<&'static str>,
 
     /// Whether to automatically infer the size from children.
     ///
-    /// If `true`, the skeleton will try to match the dimensions of its content.
+    /// If `true`, the children are rendered invisibly on first render, their
+    /// `getBoundingClientRect()` is measured, and the skeleton takes that size in pixels.
+    /// Without children, or until they are measured, `width` and `height` apply.
     #[prop_or(false)]
     pub infer_size: bool,
 
-    /// Whether the skeleton is currently visible.
+    /// Whether the rendered size is measured once and then fixed.
+    ///
+    /// After the first render the skeleton's box is measured and pinned as explicit pixel
+    /// `width` and `height`, and the `animate_on_visible` observer is disconnected after its
+    /// first intersection, so a static skeleton has no ongoing cost. Defaults to `false`.
+    #[prop_or(false)]
+    pub measure_once: bool,
+
+    /// Whether the content has loaded.
     ///
-    /// Controls whether the skeleton should be rendered or hidden.
+    /// While `false`, the skeleton is rendered in place of the children. Flipping it to `true`
+    /// at runtime removes the skeleton and renders the children; flipping it back shows the
+    /// skeleton again. Defaults to `false`.
     #[prop_or(false)]
     pub show: bool,
 
@@ -122,12 +471,84 @@ pub struct SkeletonProps {
     #[prop_or(0)]
     pub delay_ms: u32,
 
+    /// Controlled visibility of the skeleton, for parents that drive it directly.
+    ///
+    /// When set, the skeleton is shown exactly while it is `true` and the children while it
+    /// is `false`: `show`, `delay_ms`, `hide_debounce_ms`, `min_display_ms`, and `fade_out_ms`
+    /// are ignored, so the parent can reveal the content at any time, even mid-delay.
+    /// Defaults to `None` (uncontrolled, driven by `show`).
+    #[prop_or_default]
+    pub visible: Option<bool>,
+
+    /// Whether children stay mounted while the skeleton is shown.
+    ///
+    /// When `true`, children are always rendered (hidden while loading) so their component
+    /// state, such as form input, persists across loading cycles. Defaults to `false`.
+    #[prop_or(false)]
+    pub keep_children_mounted: bool,
+
+    /// Duration of the fade-out when the skeleton is revealed, in milliseconds.
+    ///
+    /// When greater than `0`, the skeleton fades out once `show` becomes `true` before the
+    /// children take its place. Defaults to `0` (instant swap).
+    #[prop_or(0)]
+    pub fade_out_ms: u32,
+
+    /// How long `show` must stay `true` before the skeleton is hidden, in milliseconds.
+    ///
+    /// Together with `delay_ms`, which only shows the skeleton once `show` has stayed `false`
+    /// for the whole delay, this coalesces rapid `show` toggles (e.g. on a flaky network)
+    /// instead of flickering. Defaults to `0`.
+    #[prop_or(0)]
+    pub hide_debounce_ms: u32,
+
+    /// Minimum time the skeleton stays on screen once shown, in milliseconds.
+    ///
+    /// When `show` becomes `true` shortly after the skeleton appeared, the hide is postponed
+    /// until it has been visible this long, so fast loads don't flash it for a single frame.
+    /// It only applies once the skeleton is shown: a skeleton mounted with a `delay_ms` starts
+    /// hidden, so data arriving within the delay still skips it entirely. Defaults to `0`.
+    #[prop_or(0)]
+    pub min_display_ms: u32,
+
+    /// Whether children mount only after the fade-out completes.
+    ///
+    /// By default children are mounted (hidden) as soon as the fade-out starts. Enabling this
+    /// defers mounting heavy children until the fade is over, so it doesn't jank.
+    #[prop_or(false)]
+    pub defer_children_until_revealed: bool,
+
+    /// Whether content is being refreshed in the background.
+    ///
+    /// While the skeleton is hidden, children stay visible and a shimmering bar is drawn along
+    /// their top edge, for "stale-while-revalidate" UIs. Defaults to `false`.
+    #[prop_or(false)]
+    pub refreshing: bool,
+
+    /// Whether revealed content is being revalidated.
+    ///
+    /// While the skeleton is hidden, a faint copy of its animation keeps running over the
+    /// children, a subtler indicator than a full skeleton. Defaults to `false`.
+    #[prop_or(false)]
+    pub revalidating: bool,
+
     /// Whether the skeleton is responsive.
     ///
-    /// Enables responsive resizing behavior based on the parent container or screen size.
+    /// The skeleton fills its container up to `width`, emitting `width: 100%;
+    /// max-width: <width>; height: auto; aspect-ratio: <ratio>;`. The ratio is
+    /// `aspect_ratio` when set, otherwise `width / height` when both use the same absolute
+    /// unit (e.g. `300px` by `150px` gives `300 / 150`); without a ratio the fixed size is
+    /// kept. Defaults to `false`.
     #[prop_or(false)]
     pub responsive: bool,
 
+    /// Whether the skeleton adapts to its container rather than the viewport.
+    ///
+    /// Wraps the skeleton in a `container-type: inline-size` element and applies `@container`
+    /// rules, so the skeleton stretches to the full width inside narrow containers.
+    #[prop_or(false)]
+    pub container_responsive: bool,
+
     /// Optional maximum width of the skeleton.
     ///
     /// Accepts any valid CSS width value (e.g., `600px`, `100%`).
@@ -152,29 +573,103 @@ pub struct SkeletonProps {
     #[prop_or(None)]
     pub min_height: Option<&'static str>,
 
+    /// Optional maximum number of text lines the skeleton may span.
+    ///
+    /// Caps the height at that many lines and shortens the final line, like `-webkit-line-clamp`,
+    /// including a height measured from the children with `infer_size`. `Some(0)` is ignored.
+    /// Relies on the CSS `lh` unit (Chrome 109, Firefox 120, Safari 16.4).
+    #[prop_or(None)]
+    pub max_lines: Option<usize>,
+
     /// Whether the skeleton animates on hover.
     ///
     /// When enabled, an animation will be triggered when the user hovers over the skeleton.
     #[prop_or(false)]
     pub animate_on_hover: bool,
 
+    /// Optional filter applied on hover when `animate_on_hover` is enabled.
+    ///
+    /// Defaults to `None`, which uses `brightness(0.95)`.
+    #[prop_or(None)]
+    pub hover_filter: Option<HoverFilter>,
+
+    /// Whether the animation pauses while the skeleton is hovered.
+    ///
+    /// Reduces distraction on interactive placeholders. Unlike `animate_on_hover`, this
+    /// doesn't change the skeleton's appearance. Defaults to `false`.
+    #[prop_or(false)]
+    pub pause_on_hover: bool,
+
     /// Whether the skeleton animates on focus.
     ///
     /// Useful for accessibility - triggers animation when the component receives focus.
     #[prop_or(false)]
     pub animate_on_focus: bool,
 
+    /// Optional outline drawn on focus when `animate_on_focus` is enabled.
+    ///
+    /// Defaults to `None`, which uses a `2px solid #999` outline.
+    #[prop_or(None)]
+    pub focus_outline: Option<FocusOutline>,
+
     /// Whether the skeleton animates on active (click or tap).
     ///
     /// Triggers animation when the skeleton is actively clicked or touched.
     #[prop_or(false)]
     pub animate_on_active: bool,
 
+    /// Whether the skeleton is hidden entirely when the page is printed.
+    ///
+    /// By default printed skeletons are rendered static and light instead.
+    #[prop_or(false)]
+    pub hide_on_print: bool,
+
+    /// Whether the skeleton ignores the defaults of a surrounding `SkeletonGroup`.
+    ///
+    /// When `true`, group-level settings such as `overlap` are not applied to this skeleton,
+    /// which keeps only its own props. Defaults to `false`.
+    #[prop_or(false)]
+    pub ignore_group_defaults: bool,
+
     /// Whether the skeleton animates when it becomes visible in the viewport.
     ///
     /// Uses `IntersectionObserver` to detect visibility and trigger animation.
     #[prop_or(false)]
     pub animate_on_visible: bool,
+
+    /// Called once the skeleton first scrolls into view, e.g. to start lazy loading.
+    ///
+    /// Fires at most once per mounted skeleton and requires `animate_on_visible`. Defaults
+    /// to `None`.
+    #[prop_or_default]
+    pub on_visible: Option<Callback<()>>,
+
+    /// Called whenever the skeleton appears or gives way to its children.
+    ///
+    /// Receives the new visibility of the skeleton: `true` once it is shown (e.g. after
+    /// `delay_ms` elapses) and `false` once it is hidden because `show` became `true`. Not
+    /// called for the initial state. Defaults to `None`.
+    #[prop_or_default]
+    pub on_reveal: Option<Callback<bool>>,
+}
+
+/// Builds props from a `SkeletonConfig`, e.g. to render `<Skeleton ..props />` from data.
+impl From<SkeletonConfig> for SkeletonProps {
+    fn from(config: SkeletonConfig) -> Self {
+        let mut props = yew::props!(SkeletonProps {});
+        props.variant = config.variant;
+        props.animation = config.animation;
+        props.direction = config.direction;
+        props.theme = config.theme;
+        props.width = config.width.into();
+        props.height = config.height.into();
+        props.border_radius = config.border_radius;
+        props.lines = config.lines;
+        props.delay_ms = config.delay_ms;
+        props.animate_on_visible = config.animate_on_visible;
+        props.custom_style = config.custom_style.into();
+        props
+    }
 }
 
 /// Skeleton Component
@@ -263,6 +758,31 @@ pub struct SkeletonProps {
 /// }
 /// ```
 ///
+/// ## Computed Values
+/// ```rust
+/// use yew::prelude::*;
+/// use skeleton_rs::yew::Skeleton;
+/// use skeleton_rs::Theme;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let columns = 3;
+///     let height = String::from("2em");
+///     let brand_color = String::from("#cbd5e1");
+///     let offset = 8;
+///     html! {
+///         <>
+///             <Skeleton width="300px" height="1em" />
+///             <Skeleton width={format!("calc(100% / {columns})")} height={height} />
+///             <Skeleton
+///                 theme={Theme::CustomOwned(brand_color)}
+///                 custom_style={format!("margin-left: {offset}px;")}
+///             />
+///         </>
+///     }
+/// }
+/// ```
+///
 /// # Behavior
 /// - When `animate_on_visible` is enabled, animation starts only once the component enters the viewport.
 /// - If `show` is set to `false`, the component initializes hidden and reveals itself based on internal or external logic.
@@ -281,26 +801,124 @@ pub struct SkeletonProps {
 #[function_component(Skeleton)]
 pub fn skeleton(props: &SkeletonProps) -> Html {
     let node_ref = use_node_ref();
-    let visible = use_state(|| !props.show);
-    let direction = props.direction.clone();
+    let contrast_color = use_state(|| None::<&'static str>);
+    let visible = use_state(|| {
+        props
+            .visible
+            .unwrap_or_else(|| mount_visible(props.show, props.delay_ms))
+    });
+    let fading = use_state(|| false);
+    // Re-sampled whenever the skeleton element is (re)inserted, which is when its animation starts.
+    let animation_started_at = *use_memo(*visible, |_| now_ms());
+    let instance = *use_memo((), |_| next_instance_id());
+    let scope_id = *use_memo((), |_| next_unique_id());
+    let id = props.id;
+
+    let provider = use_context::<SkeletonDefaults>();
+    // A provider renders the shared `<noscript>` keyframes once for every skeleton below it.
+    let noscript_shared = provider.is_some();
+    let defaults = provider.unwrap_or_default();
+    let theme = defaults.theme(props.theme.as_ref());
+    let animation = defaults.animation(props.animation.as_ref());
+    let direction = defaults.direction(props.direction.as_ref());
+
+    let reveal_scheduler = use_context::<RevealScheduler>()
+        .filter(|scheduler| scheduler.stagger_ms() > 0 && !props.ignore_group_defaults);
+    let reveal_delay = use_state(|| None::<f64>);
 
     let props_clone = props.clone();
     let visible_clone = visible.clone();
 
     {
         let visible = visible.clone();
-        use_effect_with((props_clone.show,), move |_| {
-            if props_clone.show {
-                visible.set(false);
-            } else if props_clone.delay_ms > 0 {
-                let timeout = Timeout::new(props_clone.delay_ms, move || {
-                    visible_clone.set(true);
-                });
-                timeout.forget();
-            } else {
-                visible.set(true);
+        let fading = fading.clone();
+        let reveal_delay = reveal_delay.clone();
+        let pending_timer = use_mut_ref(|| None::<Timer>);
+        use_effect_with((props_clone.show, props_clone.visible), move |_| {
+            let fade_out_ms = props_clone.fade_out_ms;
+            let reveal = {
+                let visible = visible.clone();
+                let fading = fading.clone();
+                let pending_timer = pending_timer.clone();
+                move || {
+                    let schedule_reveal = move || {
+                        if let Some(scheduler) = reveal_scheduler {
+                            reveal_delay.set(Some(scheduler.next_delay(now_ms())));
+                        }
+                    };
+                    if fade_out_ms > 0 && *visible {
+                        fading.set(true);
+                        let fade = run_after(fade_out_ms, {
+                            let fading = fading.clone();
+                            move || {
+                                trace_lifecycle!(instance, id, "revealed");
+                                schedule_reveal();
+                                visible.set(false);
+                                fading.set(false);
+                            }
+                        });
+                        *pending_timer.borrow_mut() = Some(fade);
+                    } else {
+                        trace_lifecycle!(instance, id, "revealed");
+                        if *visible {
+                            schedule_reveal();
+                        }
+                        visible.set(false);
+                    }
+                }
+            };
+            let shown_for_ms = visible.then(|| now_ms() - animation_started_at);
+            let change = visibility_change(
+                props_clone.show,
+                shown_for_ms,
+                props_clone.delay_ms,
+                props_clone.min_display_ms,
+                props_clone.hide_debounce_ms,
+            );
+            let timer = match (props_clone.visible, change) {
+                (Some(controlled), _) => {
+                    visible.set(controlled);
+                    None
+                }
+                (None, (0, false)) => {
+                    reveal();
+                    None
+                }
+                (None, (wait_ms, false)) => Some(run_after(wait_ms, reveal)),
+                (None, (0, true)) => {
+                    visible.set(true);
+                    None
+                }
+                (None, (delay_ms, true)) => {
+                    trace_lifecycle!(instance, id, delay_ms, "delay started");
+                    Some(run_after(delay_ms, move || {
+                        trace_lifecycle!(instance, id, delay_ms, "delay elapsed");
+                        visible_clone.set(true);
+                    }))
+                }
+            };
+            if let Some(timer) = timer {
+                *pending_timer.borrow_mut() = Some(timer);
+            }
+            // A flip of `show` cancels the change still pending, including a running fade.
+            move || {
+                if let Some(timer) = pending_timer.borrow_mut().take() {
+                    timer.cancel();
+                    fading.set(false);
+                }
+            }
+        });
+    }
+
+    {
+        let on_reveal = props.on_reveal.clone();
+        let previous_visible = use_mut_ref(|| *visible);
+        use_effect_with(*visible, move |visible| {
+            if previous_visible.replace(*visible) != *visible {
+                if let Some(on_reveal) = on_reveal {
+                    on_reveal.emit(*visible);
+                }
             }
-            || ()
         });
     }
 
@@ -308,215 +926,511 @@ pub fn skeleton(props: &SkeletonProps) -> Html {
         let node_ref = node_ref.clone();
         let visible = visible.clone();
 
+        let measure_once = props.measure_once;
+        let on_visible = props.on_visible.clone();
+        let notified_visible = use_mut_ref(|| false);
         use_effect_with(
             (node_ref.clone(), props.animate_on_visible),
             move |(node_ref, animate_on_visible)| {
-                if !*animate_on_visible {
-                    return;
-                }
-
-                let element = node_ref.cast::<HtmlElement>();
-                if let Some(element) = element {
+                let element = animate_on_visible
+                    .then(|| node_ref.cast::<HtmlElement>())
+                    .flatten();
+                let observer = element.and_then(|element| {
                     let cb = Closure::wrap(Box::new(
-                        move |entries: js_sys::Array, _observer: IntersectionObserver| {
+                        move |entries: js_sys::Array, observer: IntersectionObserver| {
                             for entry in entries.iter() {
                                 let entry = entry.unchecked_into::<IntersectionObserverEntry>();
                                 if entry.is_intersecting() {
+                                    trace_lifecycle!(instance, id, "intersecting");
                                     visible.set(true);
+                                    if !notified_visible.replace(true) {
+                                        if let Some(on_visible) = &on_visible {
+                                            on_visible.emit(());
+                                        }
+                                    }
+                                    if measure_once {
+                                        observer.disconnect();
+                                    }
                                 }
                             }
                         },
                     )
                         as Box<dyn FnMut(js_sys::Array, IntersectionObserver)>);
 
-                    let observer = IntersectionObserver::new(cb.as_ref().unchecked_ref()).unwrap();
+                    let observer = IntersectionObserver::new(cb.as_ref().unchecked_ref()).ok()?;
                     observer.observe(&element);
+                    Some((observer, cb))
+                });
+                move || {
+                    if let Some((observer, _cb)) = observer {
+                        observer.disconnect();
+                    }
+                }
+            },
+        );
+    }
 
-                    cb.forget();
+    {
+        let node_ref = node_ref.clone();
+        let contrast_color = contrast_color.clone();
+
+        use_effect_with(
+            (node_ref, props.auto_contrast, *visible),
+            move |(node_ref, auto_contrast, visible)| {
+                if *auto_contrast && *visible {
+                    if let Some(element) = node_ref.cast::<Element>() {
+                        contrast_color.set(auto_contrast_color(&element));
+                    }
                 }
             },
         );
     }
 
-    let background_color = match props.theme {
-        Theme::Light => "#e0e0e0",
-        Theme::Dark => "#444444",
-        Theme::Custom(color) => color,
-    };
+    let measured_size = use_state(|| None::<(f64, f64)>);
+    {
+        let node_ref = node_ref.clone();
+        let measured_size = measured_size.clone();
 
-    let effective_radius = match props.variant {
-        Variant::Circular | Variant::Avatar => "50%",
-        Variant::Rectangular => "0",
-        Variant::Rounded => "8px",
-        Variant::Button => "6px",
-        Variant::Text | Variant::Image => props.border_radius,
-    };
-    let (keyframes_name, wave_keyframes) = match direction {
-        Direction::LeftToRight => (
-            "skeleton-wave-ltr",
-            r#"
-            @keyframes skeleton-wave-ltr {
-                0%   { background-position: 200% 0; }
-                100% { background-position: -200% 0; }
-            }
-            "#,
-        ),
-        Direction::RightToLeft => (
-            "skeleton-wave-rtl",
-            r#"
-            @keyframes skeleton-wave-rtl {
-                0% { background-position: -200% 0; }
-                100% { background-position: 200% 0; }
-            }
-            "#,
-        ),
-        Direction::TopToBottom => (
-            "skeleton-wave-ttb",
-            r#"
-            @keyframes skeleton-wave-ttb {
-                0%   { background-position: 0 -200%; }
-                100% { background-position: 0 200%; }
-            }
-            "#,
-        ),
-        Direction::BottomToTop => (
-            "skeleton-wave-btt",
-            r#"
-            @keyframes skeleton-wave-btt {
-                0%   { background-position: 0 200%; }
-                100% { background-position: 0 -200%; }
-            }
-            "#,
-        ),
-        Direction::CustomAngle(_) => (
-            "skeleton-wave-custom",
-            r#"
-            @keyframes skeleton-wave-custom {
-                0%   { background-position: 200% 0; }
-                100% { background-position: -200% 0; }
-            }
-            "#,
-        ),
-    };
+        use_effect_with(
+            (node_ref, props.measure_once, *visible),
+            move |(node_ref, measure_once, _)| {
+                if *measure_once && measured_size.is_none() {
+                    if let Some(element) = node_ref.cast::<Element>() {
+                        let rect = element.get_bounding_client_rect();
+                        measured_size.set(Some((rect.width(), rect.height())));
+                    }
+                }
+            },
+        );
+    }
 
-    let base_animation = match props.animation {
-        Animation::Pulse => "animation: skeleton-rs-pulse 1.5s ease-in-out infinite;".to_string(),
+    let children_ref = use_node_ref();
+    let inferred_size = use_state(|| None::<(f64, f64)>);
+    let probe_children =
+        props.infer_size && *visible && !props.children.is_empty() && inferred_size.is_none();
+    {
+        let children_ref = children_ref.clone();
+        let inferred_size = inferred_size.clone();
 
-        Animation::Wave => {
-            let angle = match direction {
-                Direction::LeftToRight => 90,
-                Direction::RightToLeft => 90,
-                Direction::TopToBottom => 90,
-                Direction::BottomToTop => 90,
-                Direction::CustomAngle(deg) => deg,
-            };
+        use_effect_with(
+            (children_ref, probe_children),
+            move |(children_ref, probe_children)| {
+                if *probe_children {
+                    if let Some(element) = children_ref.cast::<Element>() {
+                        let rect = element.get_bounding_client_rect();
+                        inferred_size.set(Some((rect.width(), rect.height())));
+                    }
+                }
+            },
+        );
+    }
 
-            format!(
-                "background: linear-gradient({}deg, #e0e0e0 25%, #f5f5f5 50%, #e0e0e0 75%);
-                 background-size: 200% 100%;
-                 animation: {} 1.6s linear infinite;",
-                angle, keyframes_name
-            )
-        }
+    // Only `Theme::System` follows the OS preference, so no other theme queries it.
+    let system_theme = theme == Theme::System;
+    let prefers_dark = use_state(|| system_theme && prefers_dark_scheme());
+    {
+        let prefers_dark = prefers_dark.clone();
+        use_effect_with(system_theme, move |system_theme| {
+            let listener = system_theme
+                .then(|| watch_color_scheme(move |dark| prefers_dark.set(dark)))
+                .flatten();
+            move || drop(listener)
+        });
+    }
 
-        Animation::None => "".to_string(),
+    let background_color = match *contrast_color {
+        Some(color) => Cow::Borrowed(color),
+        None => theme.scheme_color(*prefers_dark),
     };
 
-    let mut style = String::new();
+    let effective_radius = radius_for(props.variant, props.border_radius);
+    let (template_layout, template_blocks) = props
+        .template
+        .and_then(|template| match parse_template(template) {
+            Ok(columns) => Some(template_layout(&columns)),
+            Err(err) => {
+                tracing::warn!("skeleton-rs: invalid template {template:?}: {err}");
+                None
+            }
+        })
+        .or_else(|| {
+            (props.variant == Variant::Table)
+                .then(|| table_layout(props.rows, props.columns, effective_radius))
+        })
+        .unzip();
+    let body_lines = clamped_lines(props.lines, props.max_lines);
+    let text_lines = (props.variant == Variant::Text)
+        .then_some(body_lines)
+        .filter(|lines| *lines > 1);
+    let wave_keyframes = direction.wave_keyframes();
+    let wave_direction = (animation == Animation::Wave).then(|| direction.clone());
 
-    if props.infer_size {
-        style.push_str(&format!(
-            "background-color: {background_color}; border-radius: {effective_radius}; display: {}; position: {}; overflow: {}; margin: {};",
-            props.display, props.position, props.overflow, props.margin
-        ));
+    let angle = direction.gradient_angle();
+
+    // Checkerboards and outlines keep their own background and shimmer through an overlay.
+    let overlay_shimmer = (props.variant == Variant::Checkerboard
+        || props.outline_only
+        || props.wave_impl == WaveImpl::Transform)
+        && animation == Animation::Wave;
+    let overlay_highlight = if props.outline_only {
+        "var(--skeleton-rs-base)"
     } else {
-        style.push_str(&format!(
-            "width: {}; height: {}; background-color: {background_color}; border-radius: {effective_radius}; display: {}; position: {}; overflow: {}; margin: {}; line-height: {};",
-            props.width, props.height, props.display, props.position, props.overflow, props.margin, props.line_height
-        ));
-    }
+        OVERLAY_HIGHLIGHT
+    };
+    let delay = props
+        .animation_delay
+        .map_or_else(String::new, |delay| format!(" {delay}"));
+    let pulse_timing = format!(
+        "{} {}{delay}",
+        props.animation_duration.unwrap_or("1.5s"),
+        props.easing.map_or("ease-in-out", Easing::timing_function)
+    );
+    let wave_timing = format!(
+        "{} {}{delay}",
+        props
+            .animation_duration
+            .map_or_else(|| wave_duration(props.sweeps_per_second), str::to_string),
+        props.easing.map_or("linear", Easing::timing_function)
+    );
+    let mut shimmer_overlay_style = overlay_shimmer.then(|| match props.wave_impl {
+        WaveImpl::Transform => format!(
+            "position: absolute; inset: 0; background: linear-gradient({angle}deg, transparent 25%, {overlay_highlight} 50%, transparent 75%); will-change: transform; animation: {} {wave_timing} infinite;",
+            direction.transform_wave_keyframes()
+        ),
+        WaveImpl::BackgroundPosition => format!(
+            "position: absolute; inset: 0; background: linear-gradient({angle}deg, transparent 25%, {overlay_highlight} 50%, transparent 75%); background-size: {}; animation: {} {wave_timing} infinite;",
+            direction.wave_background_size(),
+            direction.wave_keyframes_name()
+        ),
+    });
 
-    if let Some(size) = props.font_size {
-        style.push_str(&format!(" font-size: {size};"));
-    }
+    let base_animation = match animation {
+        Animation::Wave if overlay_shimmer => String::new(),
+        Animation::Pulse => pulse_declaration(props.pulse_mode.keyframes_name(), &pulse_timing),
 
-    if let Some(max_w) = props.max_width {
-        style.push_str(&format!(" max-width: {max_w};"));
-    }
-    if let Some(min_w) = props.min_width {
-        style.push_str(&format!(" min-width: {min_w};"));
-    }
-    if let Some(max_h) = props.max_height {
-        style.push_str(&format!(" max-height: {max_h};"));
-    }
-    if let Some(min_h) = props.min_height {
-        style.push_str(&format!(" min-height: {min_h};"));
-    }
+        Animation::Wave => wave_declaration(&direction, &wave_timing),
 
-    style.push_str(&base_animation);
-    style.push_str(props.custom_style);
+        Animation::PulseGradient => pulse_gradient_declaration(angle, &pulse_timing),
 
-    let mut class_names = String::from("skeleton-rs");
-    if props.animate_on_hover {
-        class_names.push_str(" skeleton-hover");
-    }
-    if props.animate_on_focus {
-        class_names.push_str(" skeleton-focus");
-    }
-    if props.animate_on_active {
-        class_names.push_str(" skeleton-active");
+        Animation::Shimmer => shimmer_declaration(&direction, &wave_timing),
+
+        Animation::Glow => glow_declaration(&pulse_timing),
+
+        Animation::None => "".to_string(),
+    };
+    let custom_keyframes = props
+        .keyframes
+        .as_deref()
+        .filter(|steps| !steps.is_empty())
+        .map(compile_keyframes);
+    let duration_ms = props.animation_duration.and_then(css_time_ms);
+    let animation_ms = match (&custom_keyframes, &animation, duration_ms) {
+        (None, Animation::None, _) => 0.0,
+        (_, _, Some(ms)) => ms,
+        (Some(_), _, None)
+        | (None, Animation::Pulse | Animation::PulseGradient | Animation::Glow, None) => {
+            PULSE_DURATION_MS
+        }
+        (None, Animation::Wave | Animation::Shimmer, None) => {
+            wave_duration_ms(props.sweeps_per_second)
+        }
+    };
+    let (mut base_animation, keyframes_css) = match custom_keyframes {
+        Some((name, css)) => (format!("animation: {name} {pulse_timing} infinite;"), css),
+        None => (base_animation, String::new()),
+    };
+    if props.low_power {
+        let throttle = low_power_style(animation_ms);
+        if !base_animation.is_empty() {
+            base_animation.push_str(&throttle);
+        }
+        if let Some(overlay_style) = &mut shimmer_overlay_style {
+            overlay_style.push_str(&throttle);
+        }
     }
+    let animation_ms = if props.low_power {
+        animation_ms * LOW_POWER_SLOWDOWN
+    } else {
+        animation_ms
+    };
+
+    let sync_delay = props
+        .sync_global
+        .then(|| global_sync_delay(animation_ms, animation_started_at));
+    let style_params = StyleParams {
+        variant: props.variant,
+        background_color: &background_color,
+        highlight_color: theme.highlight(),
+        pulse_min_opacity: props.pulse_min_opacity,
+        border_radius: effective_radius,
+        border_radius_top: props.border_radius_top,
+        border_radius_bottom: props.border_radius_bottom,
+        width: props.width.as_str(),
+        height: props.height.as_str(),
+        aspect_ratio: props.aspect_ratio,
+        infer_size: props.infer_size && inferred_size.is_some(),
+        zero_size_fallback: props.zero_size_fallback,
+        responsive: props.responsive,
+        root_font_size: props.root_font_size,
+        icon_size: props.icon_size,
+        thickness: props.thickness,
+        display: props.display,
+        position: props.position,
+        overflow: props.overflow,
+        margin: props.margin,
+        logical_props: props.logical_props,
+        line_height: props.line_height,
+        font_size: props.font_size,
+        max_width: props.max_width,
+        min_width: props.min_width,
+        max_height: props.max_height,
+        min_height: props.min_height,
+        grid_span: props.grid_span,
+        multi_line: text_lines.is_some(),
+        line_gap: props.line_gap,
+        max_lines: props.max_lines,
+        template_layout: template_layout.as_deref(),
+        tag: props.tag,
+        outline_only: props.outline_only,
+        inset_shadow: props.inset_shadow,
+        sync_delay: sync_delay.as_deref(),
+        measured_size: (*measured_size).or(*inferred_size),
+        wave_offset: props.wave_offset,
+        hover_filter: props.hover_filter.as_ref(),
+        focus_outline: props.focus_outline.as_ref(),
+        morph: props.morph,
+        custom_style: &props.custom_style,
+        fading_ms: (*fading).then_some(props.fade_out_ms),
+        animate_on_hover: props.animate_on_hover,
+        pause_on_hover: props.pause_on_hover,
+        respect_reduced_motion: props.respect_reduced_motion,
+        animate_on_focus: props.animate_on_focus,
+        animate_on_active: props.animate_on_active,
+        hide_on_print: props.hide_on_print,
+        container_responsive: props.container_responsive,
+        ignore_group_defaults: props.ignore_group_defaults,
+    };
+    let style = build_skeleton_style(&style_params);
+
+    let mut anim_class = (!base_animation.is_empty()).then(|| animation_class(&base_animation));
+    let scoped_css = match &mut anim_class {
+        Some(class) if props.scoped_styles => {
+            let keyframes = match animation {
+                _ if !keyframes_css.is_empty() => keyframes_css.as_str(),
+                Animation::Pulse => props.pulse_mode.keyframes(),
+                Animation::PulseGradient => PULSE_KEYFRAMES,
+                Animation::Wave => &wave_keyframes,
+                Animation::Shimmer => SHIMMER_KEYFRAMES,
+                Animation::Glow => GLOW_KEYFRAMES,
+                Animation::None => "",
+            };
+            let (scoped_class, css) = scoped_animation_css(scope_id, keyframes, &base_animation);
+            *class = scoped_class;
+            Some(css)
+        }
+        _ => None,
+    };
+    // Composite placeholders animate their blocks instead of the transparent container.
+    let blocks = match props.variant {
+        _ if template_blocks.is_some() => template_blocks,
+        Variant::Quote => Some(quote_blocks(props.quote_lines)),
+        Variant::Card => Some(card_blocks(effective_radius)),
+        Variant::Paragraph => Some(paragraph_blocks(
+            body_lines,
+            props.height.as_str(),
+            props.line_height_each,
+            props.last_line_width.unwrap_or("60%"),
+            effective_radius,
+        )),
+        _ => text_lines.map(|lines| {
+            text_line_blocks(
+                lines,
+                props.height.as_str(),
+                props.line_height_each,
+                props.last_line_width.unwrap_or("60%"),
+                effective_radius,
+            )
+        }),
+    };
+    let block_class = blocks.as_ref().and(anim_class.clone());
+    let revalidating_class = anim_class.clone().filter(|_| props.revalidating);
+    let class_names = build_class_names(
+        &style_params,
+        anim_class.as_deref().filter(|_| blocks.is_none()),
+    );
+    // Without runtime styles, a generated class has no rule and its animation is inlined.
+    let inline_anim = inline_animation(
+        anim_class.as_deref().filter(|_| scoped_css.is_none()),
+        &base_animation,
+    );
+    let style = if blocks.is_none() {
+        format!("{style}{inline_anim}")
+    } else {
+        style
+    };
+    let noscript_css = match (&animation, &anim_class) {
+        // Apps without runtime styles ship the keyframes in their own stylesheet.
+        _ if scoped_css.is_some() || !runtime_styles_enabled() => None,
+        (_, Some(anim_class)) if !keyframes_css.is_empty() => Some(format!(
+            "{keyframes_css} .{anim_class} {{ {base_animation} }}"
+        )),
+        (Animation::Pulse | Animation::PulseGradient, Some(anim_class)) => {
+            noscript_pulse_css(anim_class, &base_animation, noscript_shared)
+        }
+        _ => None,
+    };
+    let shadow_host = props.shadow_host;
+    let scoped = scoped_css.is_some();
+    // Styles are injected while rendering rather than from an effect, so the skeleton is
+    // animated from its first frame.
+    use_memo((), |_| inject_base_stylesheet(shadow_host));
+    use_memo(anim_class, move |anim_class| {
+        if let Some(anim_class) = anim_class.as_ref().filter(|_| !scoped) {
+            inject_animation_class(shadow_host, anim_class, &keyframes_css, &base_animation);
+        }
+    });
+    // Each direction has its own keyframes, so waves of several directions can coexist.
+    use_memo(wave_direction, move |direction| {
+        if let Some(direction) = direction
+            .as_ref()
+            .filter(|_| !uses_static_styles(shadow_host))
+        {
+            inject_style_in(
+                shadow_host,
+                &direction.wave_keyframes_name(),
+                &direction.wave_keyframes(),
+            );
+        }
+    });
     use_effect_with((), move |_| {
-        if let Some(doc) = window().and_then(|w| w.document()) {
-            if doc.get_element_by_id("skeleton-rs-style").is_none() {
-                let style_elem = doc.create_element("style").unwrap();
-                style_elem.set_id("skeleton-rs-style");
-                let style_css = format!(
-                    r#"
-                    @keyframes skeleton-rs-pulse {{
-                        0% {{ opacity: 1; }}
-                        25% {{ opacity: 0.7; }}
-                        50% {{ opacity: 0.4; }}
-                        75% {{ opacity: 0.7; }}
-                        100% {{ opacity: 1; }}
-                    }}
-
-                    {}
-
-                    .skeleton-hover:hover {{
-                        filter: brightness(0.95);
-                    }}
-
-                    .skeleton-focus:focus {{
-                        outline: 2px solid #999;
-                    }}
-
-                    .skeleton-active:active {{
-                        transform: scale(0.98);
-                    }}
-                    "#,
-                    wave_keyframes
-                );
-                style_elem.set_inner_html(&style_css);
-                if let Some(head) = doc.head() {
-                    head.append_child(&style_elem).unwrap();
-                }
+        trace_lifecycle!(instance, id, "mounted");
+    });
+
+    let is_button = props.tag == "button";
+    let busy = props.aria_busy || props.sr_only_text.is_some();
+    let decorative = !is_button && is_decorative(props.loading_label, busy, props.role);
+    let role =
+        props
+            .role
+            .or((!is_button).then_some(if decorative { "presentation" } else { "status" }));
+    let aria_hidden = decorative.then_some("true");
+    let aria_busy = busy.then_some("true");
+    let aria_live = aria_live(role);
+
+    let skeleton = html! {
+        <@{props.tag}
+            ref={node_ref}
+            id={id}
+            class={class_names}
+            style={style}
+            type={is_button.then_some("button")}
+            disabled={is_button}
+            aria-disabled={is_button.then_some("true")}
+            role={role}
+            aria-hidden={aria_hidden}
+            aria-busy={aria_busy}
+            aria-live={aria_live}
+            aria-label={props.loading_label}
+            aria-describedby={props.aria_describedby}
+            data-variant={props.variant.as_str()}
+            data-animation={animation.as_str()}
+            data-theme={theme.as_str()}
+        >
+            if let Some(css) = scoped_css {
+                <style>{ css }</style>
+            }
+            if let Some(blocks) = blocks {
+                { for blocks.into_iter().map(|block| html! {
+                    <span class={block_class.clone()} style={format!("{block}{inline_anim}")} />
+                }) }
+            }
+            if let Some(overlay_style) = shimmer_overlay_style {
+                <span style={overlay_style} />
+            }
+            if let Some(text) = props.sr_only_text {
+                <span style={SR_ONLY_STYLE}>{ text }</span>
+            }
+            if let Some(css) = noscript_css {
+                <noscript><style>{ css }</style></noscript>
             }
+        </@>
+    };
+    let skeleton = if props.container_responsive {
+        html! {
+            <div class="skeleton-rs-container" style="container-type: inline-size;">
+                { skeleton }
+            </div>
         }
-    });
+    } else {
+        skeleton
+    };
+    let skeleton = if probe_children {
+        html! {
+            <>
+                { skeleton }
+                <div ref={children_ref} style={INFER_SIZE_PROBE_STYLE}>
+                    { for props.children.iter() }
+                </div>
+            </>
+        }
+    } else {
+        skeleton
+    };
 
-    if *visible {
+    let highlight_var = theme.highlight().map_or_else(String::new, |highlight| {
+        format!(" --skeleton-rs-highlight: {highlight};")
+    });
+    let children = if props.refreshing || revalidating_class.is_some() {
         html! {
             <div
-                ref={node_ref}
-                class={class_names}
-                style={style}
-                role="presentation"
-                aria-hidden="true"
-            />
+                class={props.refreshing.then_some("skeleton-refreshing")}
+                style={format!("position: relative; --skeleton-rs-base: {background_color};{highlight_var}")}
+                aria-busy="true"
+            >
+                { for props.children.iter() }
+                if let Some(class) = revalidating_class {
+                    <span style={REVALIDATING_OVERLAY_STYLE}>
+                        <span class={class} style={format!("{REVALIDATING_FILL_STYLE}{inline_anim}")} />
+                    </span>
+                }
+            </div>
         }
     } else {
         html! { <>{ for props.children.iter() }</> }
+    };
+
+    let children = match *reveal_delay {
+        Some(delay) => html! {
+            <div class="skeleton-reveal" style={format!("animation-delay: {delay:.0}ms;")}>
+                { children }
+            </div>
+        },
+        None => children,
+    };
+
+    if props.keep_children_mounted || props.fade_out_ms > 0 {
+        let mount_children = props.keep_children_mounted
+            || !*visible
+            || (*fading && !props.defer_children_until_revealed);
+        let children_style = if *visible {
+            "display: none;"
+        } else {
+            "display: contents;"
+        };
+        html! {
+            <>
+                if *visible {
+                    { skeleton }
+                }
+                if mount_children {
+                    <div style={children_style}>{ children }</div>
+                }
+            </>
+        }
+    } else if *visible {
+        skeleton
+    } else {
+        children
     }
 }
 
@@ -530,9 +1444,517 @@ pub struct SkeletonGroupProps {
 
     #[prop_or_default]
     pub class: &'static str,
+
+    /// Optional amount by which consecutive children overlap, e.g. `"12px"`.
+    ///
+    /// Applies a negative left margin to every child after the first, so grouped
+    /// skeletons stack like a fanned deck of cards.
+    #[prop_or(None)]
+    pub overlap: Option<&'static str>,
+
+    /// Whether `overlap` uses logical margins.
+    ///
+    /// When `true`, children overlap along the inline direction, so the stack flips in
+    /// right-to-left layouts. Defaults to `false`.
+    #[prop_or(false)]
+    pub logical_props: bool,
+
+    /// Optional pair of base colors alternated between odd and even children.
+    ///
+    /// Zebra-stripes long list placeholders for readability; the first color applies to
+    /// odd children. Defaults to `None`.
+    #[prop_or(None)]
+    pub stripe_colors: Option<(&'static str, &'static str)>,
+
+    /// Minimum delay between the reveals of consecutive children, in milliseconds.
+    ///
+    /// When greater than `0`, each child fades its content in as its `show` flips, at least
+    /// this long after the previous one, for a smooth streaming-load effect. Defaults to `0`.
+    #[prop_or(0)]
+    pub reveal_stagger_ms: u32,
+
+    /// Optional name of a stagger timeline shared with other groups.
+    ///
+    /// Groups with the same timeline reveal their children as one coordinated sequence,
+    /// continuing the stagger across groups. Defaults to `None`.
+    #[prop_or(None)]
+    pub timeline: Option<&'static str>,
+
+    /// Space between children, as a CSS length or a spacing token, e.g. `Spacing::Md`.
+    ///
+    /// Tokens resolve through the scale registered with `register_spacing_scale`, so
+    /// grouped skeletons follow the app's spacing tokens. Applies when `direction` is set
+    /// and between repeated `template` items. Defaults to `"0.5rem"`.
+    #[prop_or("0.5rem")]
+    pub gap: &'static str,
+
+    /// Optional main axis of a flexbox layout, e.g. `GroupDirection::Column`.
+    ///
+    /// When set, the container becomes a flex container spaced by `gap`; `style` is
+    /// appended afterwards and can override it. Defaults to `None`.
+    #[prop_or(None)]
+    pub direction: Option<GroupDirection>,
+
+    /// Optional props of a skeleton repeated `count` times after the children.
+    ///
+    /// Renders lists of identical rows without writing each one out, e.g.
+    /// `yew::props!(SkeletonProps { width: "100%" })`. Repeated items are spaced by `gap`.
+    /// Defaults to `None`.
+    #[prop_or(None)]
+    pub template: Option<SkeletonProps>,
+
+    /// Number of copies of `template` rendered.
+    ///
+    /// Ignored without a `template`. Defaults to `1`.
+    #[prop_or(1)]
+    pub count: usize,
+}
+
+impl IntoPropValue<&'static str> for Spacing {
+    fn into_prop_value(self) -> &'static str {
+        self.css_value()
+    }
 }
 
 #[function_component(SkeletonGroup)]
 pub fn skeleton_group(props: &SkeletonGroupProps) -> Html {
-    html! { <div style={props.style} class={props.class}>{ for props.children.iter() }</div> }
+    let mut class_names = String::from(props.class);
+    let mut style = String::new();
+    if let Some(overlap) = props.overlap {
+        class_names.push_str(" skeleton-group-overlap");
+        if props.logical_props {
+            class_names.push_str(" skeleton-group-logical");
+        }
+        style.push_str(&format!("--skeleton-group-overlap: {overlap};"));
+    }
+    if let Some((odd, even)) = props.stripe_colors {
+        class_names.push_str(" skeleton-group-striped");
+        style.push_str(&format!(
+            "--skeleton-group-stripe-odd: {odd}; --skeleton-group-stripe-even: {even};"
+        ));
+    }
+    match props.direction {
+        Some(direction) => style.push_str(&format!(
+            "display: flex; flex-direction: {}; gap: {};",
+            direction.css_value(),
+            props.gap
+        )),
+        None if props.template.is_some() => {
+            style.push_str(&format!("display: grid; gap: {};", props.gap));
+        }
+        None => {}
+    }
+    style.push_str(props.style);
+
+    let reveal_scheduler = use_memo(
+        (props.reveal_stagger_ms, props.timeline),
+        |(stagger_ms, timeline)| match timeline {
+            Some(timeline) => RevealScheduler::shared(timeline, *stagger_ms),
+            None => RevealScheduler::new(*stagger_ms),
+        },
+    );
+
+    html! {
+        <ContextProvider<RevealScheduler> context={(*reveal_scheduler).clone()}>
+            <div style={style} class={class_names}>
+                { for props.children.iter() }
+                if let Some(template) = &props.template {
+                    { for (0..props.count).map(|_| html! { <Skeleton ..template.clone() /> }) }
+                }
+            </div>
+        </ContextProvider<RevealScheduler>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SkeletonProviderProps {
+    /// Theme of the skeletons inside the provider that do not set `theme`.
+    #[prop_or_default]
+    pub theme: Option<Theme>,
+
+    /// Animation of the skeletons inside the provider that do not set `animation`.
+    #[prop_or_default]
+    pub animation: Option<Animation>,
+
+    /// Direction of the skeletons inside the provider that do not set `direction`.
+    #[prop_or_default]
+    pub direction: Option<Direction>,
+
+    /// CSP nonce set as the `nonce` attribute of every `<style>` element the skeletons inject,
+    /// for pages served with a `style-src 'nonce-...'` Content-Security-Policy.
+    #[prop_or_default]
+    pub nonce: Option<AttrValue>,
+
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Supplies app-wide defaults to every skeleton rendered inside it.
+///
+/// Skeletons resolve `theme`, `animation`, and `direction` with the precedence explicit
+/// prop > provider > enum default. Nested providers inherit what they do not set from the
+/// enclosing one.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use skeleton_rs::yew::{Skeleton, SkeletonProvider};
+/// use skeleton_rs::{Animation, Theme};
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <SkeletonProvider theme={Theme::Dark} animation={Animation::Wave}>
+///             <Skeleton width="200px" />
+///             <Skeleton width="120px" theme={Theme::Light} />
+///         </SkeletonProvider>
+///     }
+/// }
+/// ```
+#[function_component(SkeletonProvider)]
+pub fn skeleton_provider(props: &SkeletonProviderProps) -> Html {
+    // Set while rendering, so it is in place before the skeletons below inject their styles.
+    if let Some(nonce) = &props.nonce {
+        set_style_nonce(nonce);
+    }
+    let outer = use_context::<SkeletonDefaults>();
+    // Only the outermost provider ships the `<noscript>` keyframes.
+    let noscript_css = (outer.is_none() && runtime_styles_enabled()).then(noscript_shared_css);
+    let outer = outer.unwrap_or_default();
+    let defaults = SkeletonDefaults {
+        theme: props.theme.clone(),
+        animation: props.animation.clone(),
+        direction: props.direction.clone(),
+    }
+    .inherit(&outer);
+
+    html! {
+        <ContextProvider<SkeletonDefaults> context={defaults}>
+            if let Some(css) = noscript_css {
+                <noscript>
+                    <style id={NOSCRIPT_STYLE_ID} nonce={props.nonce.clone()}>{ css }</style>
+                </noscript>
+            }
+            { for props.children.iter() }
+        </ContextProvider<SkeletonDefaults>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SkeletonListItemProps {
+    /// Width and height of the circular avatar.
+    ///
+    /// Defaults to `"2.5em"`.
+    #[prop_or("2.5em")]
+    pub avatar_size: &'static str,
+
+    /// Number of text lines stacked next to the avatar, e.g. a name and a subtitle.
+    ///
+    /// The last line is drawn shorter. Defaults to `2`.
+    #[prop_or(2)]
+    pub lines: usize,
+
+    /// The theme of the avatar and text skeletons.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s theme, or `Theme::Light`.
+    #[prop_or_default]
+    pub theme: Option<Theme>,
+
+    /// The animation of the avatar and text skeletons.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s animation, or `Animation::Pulse`.
+    #[prop_or_default]
+    pub animation: Option<Animation>,
+
+    #[prop_or_default]
+    pub style: &'static str,
+
+    #[prop_or_default]
+    pub class: &'static str,
+}
+
+/// List row placeholder: a circular avatar next to stacked text lines.
+///
+/// Composes an `Avatar` and a `Text` skeleton, e.g. for chat or contact lists.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use skeleton_rs::yew::SkeletonListItem;
+///
+/// #[function_component(Contacts)]
+/// pub fn contacts() -> Html {
+///     html! {
+///         <>
+///             { for (0..4).map(|_| html! { <SkeletonListItem avatar_size="3em" /> }) }
+///         </>
+///     }
+/// }
+/// ```
+#[function_component(SkeletonListItem)]
+pub fn skeleton_list_item(props: &SkeletonListItemProps) -> Html {
+    html! {
+        <div
+            class={props.class}
+            style={format!("{LIST_ITEM_LAYOUT}{}", props.style)}
+        >
+            <Skeleton
+                variant={Variant::Avatar}
+                width={props.avatar_size}
+                height={props.avatar_size}
+                theme={props.theme.clone()}
+                animation={props.animation.clone()}
+            />
+            <Skeleton
+                variant={Variant::Text}
+                lines={props.lines}
+                width="100%"
+                height="0.9em"
+                display="block"
+                theme={props.theme.clone()}
+                animation={props.animation.clone()}
+                custom_style={LIST_ITEM_TEXT_STYLE}
+            />
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SkeletonTextProps {
+    /// Number of lines in the paragraph.
+    ///
+    /// Defaults to `3`.
+    #[prop_or(3)]
+    pub lines: usize,
+
+    /// Seed of the line widths, see `text_line_widths`.
+    ///
+    /// Paragraphs with different seeds get different widths; the same seed always renders the
+    /// same widths, on the server and in the browser. Defaults to `None` (seed `0`).
+    #[prop_or_default]
+    pub seed: Option<u64>,
+
+    /// Explicit width of each line, overriding the generated ones.
+    ///
+    /// Lines without an entry keep their generated width. Defaults to `None`.
+    #[prop_or_default]
+    pub widths: Option<Vec<String>>,
+
+    /// Height of each line.
+    ///
+    /// Defaults to `"1em"`.
+    #[prop_or("1em")]
+    pub height: &'static str,
+
+    /// Gap between the lines.
+    ///
+    /// Defaults to `"0.5em"`.
+    #[prop_or("0.5em")]
+    pub line_gap: &'static str,
+
+    /// The theme of the lines.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s theme, or `Theme::Light`.
+    #[prop_or_default]
+    pub theme: Option<Theme>,
+
+    /// The animation of the lines.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s animation, or `Animation::Pulse`.
+    #[prop_or_default]
+    pub animation: Option<Animation>,
+
+    #[prop_or_default]
+    pub style: &'static str,
+
+    #[prop_or_default]
+    pub class: &'static str,
+}
+
+/// Paragraph placeholder whose lines vary in width like real text.
+///
+/// Every line is a `Text` skeleton whose width comes from `text_line_widths`, so the
+/// paragraph looks natural while rendering identically on the server and the client.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use skeleton_rs::yew::SkeletonText;
+///
+/// #[function_component(Article)]
+/// pub fn article() -> Html {
+///     html! {
+///         <>
+///             <SkeletonText lines={4} seed={1} />
+///             <SkeletonText lines={2} widths={vec!["100%".to_string(), "30%".to_string()]} />
+///         </>
+///     }
+/// }
+/// ```
+#[function_component(SkeletonText)]
+pub fn skeleton_text(props: &SkeletonTextProps) -> Html {
+    let widths = text_line_widths(props.lines, props.seed.unwrap_or_default());
+    let widths = widths.into_iter().enumerate().map(|(line, width)| {
+        props
+            .widths
+            .as_ref()
+            .and_then(|widths| widths.get(line).cloned())
+            .unwrap_or(width)
+    });
+
+    html! {
+        <div
+            class={props.class}
+            style={format!("{}{}", text_lines_layout(props.line_gap), props.style)}
+        >
+            { for widths.map(|width| html! {
+                <Skeleton
+                    variant={Variant::Text}
+                    width={width}
+                    height={props.height}
+                    display="block"
+                    theme={props.theme.clone()}
+                    animation={props.animation.clone()}
+                />
+            }) }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SkeletonMirrorProps {
+    /// Reference to the rendered element whose layout is mirrored.
+    pub target: NodeRef,
+
+    /// The theme of the mirrored placeholders.
+    ///
+    /// Defaults to the enclosing `SkeletonProvider`'s theme, or `Theme::Light`.
+    #[prop_or_default]
+    pub theme: Option<Theme>,
+
+    /// Border radius of every mirrored placeholder.
+    ///
+    /// Defaults to `"4px"`.
+    #[prop_or("4px")]
+    pub border_radius: &'static str,
+
+    /// Whether the mirrored placeholders pulse.
+    ///
+    /// Defaults to `true`.
+    #[prop_or(true)]
+    pub animate: bool,
+}
+
+/// Skeleton mirroring the box structure of an already-rendered element.
+///
+/// Every leaf element of `target` (text blocks, images, buttons, ...) is measured and
+/// replaced by a placeholder of the same size and position, so the loading state matches
+/// the loaded layout exactly. The target is observed with `ResizeObserver` and re-measured
+/// whenever its size changes.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use skeleton_rs::yew::SkeletonMirror;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let card = use_node_ref();
+///     let loading = use_state(|| true);
+///     html! {
+///         <>
+///             <div ref={card.clone()} hidden={*loading}>
+///                 <h3>{ "Title" }</h3>
+///                 <p>{ "Body" }</p>
+///             </div>
+///             if *loading {
+///                 <SkeletonMirror target={card} />
+///             }
+///         </>
+///     }
+/// }
+/// ```
+#[function_component(SkeletonMirror)]
+pub fn skeleton_mirror(props: &SkeletonMirrorProps) -> Html {
+    let layout = use_state(|| None::<(MirrorBox, Vec<MirrorBox>)>);
+    let defaults = use_context::<SkeletonDefaults>().unwrap_or_default();
+
+    {
+        let layout = layout.clone();
+        use_effect_with(props.target.clone(), move |target| {
+            inject_style("skeleton-rs-mirror-style", PULSE_KEYFRAMES);
+            let observer = target.cast::<Element>().and_then(|element| {
+                layout.set(Some(measure_mirror(&element)));
+                let observed = element.clone();
+                let cb = Closure::wrap(Box::new(
+                    move |_entries: js_sys::Array, _observer: ResizeObserver| {
+                        layout.set(Some(measure_mirror(&observed)));
+                    },
+                )
+                    as Box<dyn FnMut(js_sys::Array, ResizeObserver)>);
+                let observer = ResizeObserver::new(cb.as_ref().unchecked_ref()).ok()?;
+                observer.observe(&element);
+                Some((observer, cb))
+            });
+            move || {
+                if let Some((observer, _cb)) = observer {
+                    observer.disconnect();
+                }
+            }
+        });
+    }
+
+    let Some((root, boxes)) = &*layout else {
+        return html! {};
+    };
+    let base_color = defaults.theme(props.theme.as_ref()).base_color();
+
+    html! {
+        <div
+            class="skeleton-rs-mirror"
+            style={format!("position: relative; width: {}px; height: {}px;", root.width, root.height)}
+            role="presentation"
+            aria-hidden="true"
+        >
+            { for boxes.iter().map(|mirror_box| html! {
+                <div
+                    class="skeleton-rs"
+                    style={mirror_box_style(mirror_box, &base_color, props.border_radius, props.animate)}
+                />
+            }) }
+        </div>
+    }
+}
+
+/// Returns a class that draws a skeleton over the element it is applied to.
+///
+/// The skeleton is rendered by a `::before` pseudo-element, so no wrapper node is added.
+/// Apply the class while loading and remove it once the content is ready. See
+/// `AttachedSkeleton` for the available options.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use skeleton_rs::yew::use_skeleton_class;
+/// use skeleton_rs::AttachedSkeleton;
+///
+/// #[function_component(Card)]
+/// pub fn card() -> Html {
+///     let loading = use_state(|| true);
+///     let skeleton = use_skeleton_class(AttachedSkeleton::default());
+///     html! {
+///         <div class={classes!("card", loading.then_some(skeleton))}>{ "Content" }</div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_skeleton_class(skeleton: AttachedSkeleton) -> String {
+    let (class, css) = skeleton.stylesheet();
+    use_effect_with(class.clone(), move |class| inject_style(class, &css));
+    class
 }
@@ -0,0 +1,242 @@
+//! Browser tests of the Dioxus backend.
+//!
+//! Run with `wasm-pack test --headless --firefox --features dio`.
+//!
+//! Every test launches its own app into a fresh element, so skeletons with generated ids
+//! would share them across apps; tests that look elements up by id pass an explicit one.
+#![cfg(all(target_arch = "wasm32", feature = "dio"))]
+
+use dioxus::prelude::*;
+use dioxus::web::Config;
+use dioxus::web::launch::launch_virtual_dom;
+use gloo_timers::future::TimeoutFuture;
+use skeleton_rs::dioxus::Skeleton;
+use skeleton_rs::{Animation, Direction};
+use std::cell::RefCell;
+use wasm_bindgen_test::*;
+use web_sys::js_sys;
+use web_sys::wasm_bindgen::JsCast;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Wraps `IntersectionObserver.prototype.disconnect` to count its calls, once per page.
+const DISCONNECT_SPY: &str = "
+if (window.skeletonDisconnects === undefined) {
+    window.skeletonDisconnects = 0;
+    const disconnect = IntersectionObserver.prototype.disconnect;
+    IntersectionObserver.prototype.disconnect = function () {
+        window.skeletonDisconnects += 1;
+        return disconnect.call(this);
+    };
+}
+window.skeletonDisconnects";
+
+/// Returns how many times an `IntersectionObserver` was disconnected on the page so far.
+fn disconnect_count() -> u32 {
+    js_sys::eval(DISCONNECT_SPY).unwrap().as_f64().unwrap() as u32
+}
+
+/// Renders `app` into an empty element appended to the page.
+fn launch(app: fn() -> Element) -> web_sys::Element {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let root = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&root).unwrap();
+    launch_virtual_dom(
+        VirtualDom::new(app),
+        Config::new().rootelement(root.clone()),
+    );
+    root
+}
+
+/// Yields to the browser long enough for Dioxus to render and observers to report.
+async fn settle() {
+    TimeoutFuture::new(100).await;
+}
+
+/// Returns the generated `skeleton-rs-anim-*` class of the element with `id`.
+fn animation_class_of(root: &web_sys::Element, id: &str) -> String {
+    root.query_selector(&format!("#{id}"))
+        .unwrap()
+        .unwrap()
+        .class_name()
+        .split_whitespace()
+        .find(|class| class.starts_with("skeleton-rs-anim-"))
+        .map(str::to_string)
+        .unwrap()
+}
+
+/// Returns the text of the stylesheet injected under `id`.
+fn stylesheet(id: &str) -> String {
+    let document = web_sys::window().unwrap().document().unwrap();
+    document
+        .get_element_by_id(id)
+        .unwrap()
+        .text_content()
+        .unwrap()
+}
+
+fn measured() -> Element {
+    rsx! {
+        Skeleton {
+            id: "measured",
+            animate_on_visible: true,
+            measure_once: true,
+            width: "200px",
+            height: "20px",
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+async fn measure_once_disconnects_observer_after_measuring() {
+    let before = disconnect_count();
+    let root = launch(measured);
+    settle().await;
+    assert!(root.query_selector("#measured").unwrap().is_some());
+    assert_eq!(disconnect_count() - before, 1);
+}
+
+fn unmounting() -> Element {
+    let mut mounted = use_signal(|| true);
+    use_future(move || async move {
+        TimeoutFuture::new(200).await;
+        mounted.set(false);
+    });
+    rsx! {
+        if mounted() {
+            Skeleton { id: "unmounting", animate_on_visible: true, height: "20px" }
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+async fn unmounting_disconnects_observer() {
+    let before = disconnect_count();
+    let root = launch(unmounting);
+    settle().await;
+    assert!(root.query_selector("#unmounting").unwrap().is_some());
+    assert_eq!(disconnect_count(), before);
+
+    // The app removes the skeleton 200ms after launching.
+    TimeoutFuture::new(200).await;
+    assert!(root.query_selector("#unmounting").unwrap().is_none());
+    assert_eq!(disconnect_count() - before, 1);
+}
+
+thread_local! {
+    static REVEALED: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+fn three_visible() -> Element {
+    rsx! {
+        for index in 0..3 {
+            Skeleton {
+                animate_on_visible: true,
+                height: "20px",
+                on_visible: move |_| REVEALED.with_borrow_mut(|revealed| revealed.push(index)),
+            }
+            div { style: "height: 200vh;" }
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+async fn visible_skeletons_are_observed_independently() {
+    let root = launch(three_visible);
+    settle().await;
+    let skeletons = root.query_selector_all(".skeleton-rs").unwrap();
+    assert_eq!(skeletons.length(), 3);
+    let skeleton = |index| {
+        skeletons
+            .item(index)
+            .unwrap()
+            .unchecked_into::<web_sys::Element>()
+    };
+    let (first, second, third) = (skeleton(0).id(), skeleton(1).id(), skeleton(2).id());
+    assert!(first != second && second != third && first != third);
+
+    let revealed = |index| REVEALED.with_borrow(|revealed| revealed.contains(&index));
+    for index in 0..3 {
+        // Each skeleton sits two viewports below the previous one.
+        if index > 0 {
+            assert!(!revealed(index as usize));
+        }
+        skeleton(index).scroll_into_view();
+        settle().await;
+        assert!(revealed(index as usize));
+    }
+}
+
+fn opposite_waves() -> Element {
+    // A non-default duration gives both skeletons a generated animation class.
+    rsx! {
+        Skeleton {
+            id: "ltr-wave",
+            animation: Animation::Wave,
+            direction: Direction::LeftToRight,
+            animation_duration: "2s",
+        }
+        Skeleton {
+            id: "rtl-wave",
+            animation: Animation::Wave,
+            direction: Direction::RightToLeft,
+            animation_duration: "2s",
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+async fn opposite_waves_use_their_own_keyframes() {
+    let root = launch(opposite_waves);
+    settle().await;
+    for (id, keyframes) in [
+        ("ltr-wave", "skeleton-rs-wave-ltr"),
+        ("rtl-wave", "skeleton-rs-wave-rtl"),
+    ] {
+        assert!(stylesheet(keyframes).contains(&format!("@keyframes {keyframes}")));
+        let rule = stylesheet(&animation_class_of(&root, id));
+        assert!(
+            rule.contains(&format!("animation: {keyframes} ")),
+            "{id}: {rule}"
+        );
+    }
+    assert_ne!(
+        animation_class_of(&root, "ltr-wave"),
+        animation_class_of(&root, "rtl-wave")
+    );
+}
+
+fn announced() -> Element {
+    rsx! {
+        Skeleton { id: "decorative" }
+        Skeleton { id: "labelled", loading_label: "Loading profile" }
+        Skeleton { id: "button", tag: "button" }
+    }
+}
+
+#[wasm_bindgen_test]
+async fn aria_attributes_follow_labels_and_tag() {
+    let root = launch(announced);
+    settle().await;
+    let attribute = |id: &str, name: &str| {
+        root.query_selector(&format!("#{id}"))
+            .unwrap()
+            .unwrap()
+            .get_attribute(name)
+    };
+
+    assert_eq!(
+        attribute("decorative", "aria-hidden").as_deref(),
+        Some("true")
+    );
+    assert_eq!(
+        attribute("decorative", "role").as_deref(),
+        Some("presentation")
+    );
+
+    assert_eq!(attribute("labelled", "aria-hidden"), None);
+    assert_eq!(attribute("labelled", "role").as_deref(), Some("status"));
+
+    assert_eq!(attribute("button", "aria-hidden"), None);
+    assert_eq!(attribute("button", "role"), None);
+}
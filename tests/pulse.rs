@@ -0,0 +1,74 @@
+//! Browser test comparing the pulse keyframes injected by the Yew and Dioxus backends.
+//!
+//! Run with `wasm-pack test --headless --firefox --features yew,dio`.
+#![cfg(all(target_arch = "wasm32", feature = "yew", feature = "dio"))]
+
+use dioxus::prelude::*;
+use dioxus::web::Config;
+use dioxus::web::launch::launch_virtual_dom;
+use gloo_timers::future::TimeoutFuture;
+use skeleton_rs::common::PULSE_KEYFRAMES;
+use skeleton_rs::remove_styles;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Appends an empty element to the page for an app to render into.
+fn mount_point() -> web_sys::Element {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let root = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&root).unwrap();
+    root
+}
+
+/// Returns the `skeleton-rs-pulse` keyframes of the injected base stylesheet.
+fn injected_pulse_keyframes() -> String {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let css = document
+        .get_element_by_id("skeleton-rs-style")
+        .unwrap()
+        .text_content()
+        .unwrap();
+    let start = css.find("@keyframes skeleton-rs-pulse {").unwrap();
+    let mut depth = 0;
+    for (offset, c) in css[start..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 1 => return css[start..=start + offset].to_string(),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    panic!("unterminated pulse keyframes");
+}
+
+#[yew::function_component(YewPulse)]
+fn yew_pulse() -> yew::Html {
+    yew::html! { <skeleton_rs::yew::Skeleton /> }
+}
+
+fn dioxus_pulse() -> Element {
+    rsx! {
+        skeleton_rs::dioxus::Skeleton {}
+    }
+}
+
+#[wasm_bindgen_test]
+async fn backends_inject_identical_pulse_keyframes() {
+    remove_styles();
+    let app = yew::Renderer::<YewPulse>::with_root(mount_point()).render();
+    TimeoutFuture::new(50).await;
+    let yew_keyframes = injected_pulse_keyframes();
+    app.destroy();
+
+    remove_styles();
+    launch_virtual_dom(
+        VirtualDom::new(dioxus_pulse),
+        Config::new().rootelement(mount_point()),
+    );
+    TimeoutFuture::new(50).await;
+    let dioxus_keyframes = injected_pulse_keyframes();
+
+    assert_eq!(yew_keyframes, dioxus_keyframes);
+    assert_eq!(yew_keyframes, PULSE_KEYFRAMES);
+}
@@ -0,0 +1,98 @@
+//! Server-side rendering tests of the Yew and Leptos backends.
+//!
+//! Run with `cargo test --features yew,lep --test ssr`, and again with `no-runtime-style`
+//! added to cover apps shipping their own stylesheet.
+#![cfg(not(target_arch = "wasm32"))]
+
+#[cfg(feature = "yew")]
+mod yew_ssr {
+    use skeleton_rs::yew::{Skeleton, SkeletonProvider};
+    use yew::prelude::*;
+
+    fn render<C: BaseComponent<Properties = ()>>() -> String {
+        futures::executor::block_on(yew::ServerRenderer::<C>::new().render())
+    }
+
+    #[function_component(Plain)]
+    fn plain() -> Html {
+        html! { <Skeleton width="200px" /> }
+    }
+
+    #[test]
+    fn renders_skeleton_markup() {
+        let html = render::<Plain>();
+        assert!(html.contains(r#"class="skeleton-rs skeleton-rs--pulse"#));
+        assert!(html.contains(r#"role="presentation""#));
+        assert!(html.contains(r#"data-variant="text""#));
+        assert!(html.contains("width: 200px;"));
+        if cfg!(feature = "no-runtime-style") {
+            assert!(!html.contains("<style"));
+        }
+    }
+
+    #[cfg(not(feature = "no-runtime-style"))]
+    #[test]
+    fn ships_noscript_pulse_keyframes() {
+        use skeleton_rs::common::PULSE_KEYFRAMES;
+
+        let html = render::<Plain>();
+        assert!(html.contains("<noscript><style>"));
+        assert!(html.contains(PULSE_KEYFRAMES));
+    }
+
+    #[function_component(Provided)]
+    fn provided() -> Html {
+        html! {
+            <SkeletonProvider>
+                <Skeleton width="200px" />
+                <Skeleton width="120px" />
+            </SkeletonProvider>
+        }
+    }
+
+    #[cfg(not(feature = "no-runtime-style"))]
+    #[test]
+    fn provider_ships_noscript_keyframes_once() {
+        use skeleton_rs::common::NOSCRIPT_STYLE_ID;
+
+        let html = render::<Provided>();
+        assert_eq!(html.matches("@keyframes skeleton-rs-pulse {").count(), 1);
+        assert!(html.contains(&format!(r#"id="{NOSCRIPT_STYLE_ID}""#)));
+        assert_eq!(html.matches(r#"class="skeleton-rs "#).count(), 2);
+    }
+
+    #[function_component(Delayed)]
+    fn delayed() -> Html {
+        html! {
+            <Skeleton show={false} delay_ms={300}>
+                <p class="content">{ "Loaded" }</p>
+            </Skeleton>
+        }
+    }
+
+    #[test]
+    fn delayed_skeleton_is_visible_without_client() {
+        // No timer runs on the server, so the skeleton is rendered right away.
+        let html = render::<Delayed>();
+        assert!(html.contains(r#"class="skeleton-rs "#));
+        assert!(!html.contains("Loaded"));
+    }
+}
+
+#[cfg(feature = "lep")]
+mod leptos_ssr {
+    use leptos::prelude::*;
+    use skeleton_rs::leptos::Skeleton;
+
+    #[test]
+    fn renders_skeleton_markup() {
+        let html = view! { <Skeleton width="200px" /> }.to_html();
+        assert!(html.contains(r#"class="skeleton-rs skeleton-rs--pulse"#));
+        assert!(html.contains(r#"role="presentation""#));
+        assert!(html.contains(r#"data-variant="text""#));
+        assert!(html.contains("width: 200px;"));
+        if cfg!(feature = "no-runtime-style") {
+            assert!(!html.contains("<style"));
+        }
+    }
+}
@@ -0,0 +1,408 @@
+//! Browser tests of the Yew backend.
+//!
+//! Run with `wasm-pack test --headless --firefox --features yew`.
+#![cfg(all(target_arch = "wasm32", feature = "yew"))]
+
+use skeleton_rs::yew::Skeleton;
+use skeleton_rs::{Animation, Direction, Theme, remove_styles};
+use std::time::Duration;
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use web_sys::js_sys;
+use web_sys::wasm_bindgen::JsCast;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Appends an empty element to the page for a test app to render into.
+fn mount_point() -> Element {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let root = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&root).unwrap();
+    root
+}
+
+/// Yields to the browser so that Yew renders and runs its effects.
+async fn settle() {
+    sleep(Duration::from_millis(20)).await;
+}
+
+/// Wraps `IntersectionObserver.prototype.disconnect` to count its calls, once per page.
+const DISCONNECT_SPY: &str = "
+if (window.skeletonDisconnects === undefined) {
+    window.skeletonDisconnects = 0;
+    const disconnect = IntersectionObserver.prototype.disconnect;
+    IntersectionObserver.prototype.disconnect = function () {
+        window.skeletonDisconnects += 1;
+        return disconnect.call(this);
+    };
+}
+window.skeletonDisconnects";
+
+/// Returns how many times an `IntersectionObserver` was disconnected on the page so far.
+fn disconnect_count() -> u32 {
+    js_sys::eval(DISCONNECT_SPY).unwrap().as_f64().unwrap() as u32
+}
+
+fn contains(root: &Element, selector: &str) -> bool {
+    root.query_selector(selector).unwrap().is_some()
+}
+
+/// Returns the generated `skeleton-rs-anim-*` class of a skeleton element.
+fn animation_class_of(skeleton: &Element) -> String {
+    skeleton
+        .class_name()
+        .split_whitespace()
+        .find(|class| class.starts_with("skeleton-rs-anim-"))
+        .map(str::to_string)
+        .unwrap()
+}
+
+/// Returns the text of the stylesheet injected under `id`.
+fn stylesheet(id: &str) -> String {
+    let document = web_sys::window().unwrap().document().unwrap();
+    document
+        .get_element_by_id(id)
+        .unwrap()
+        .text_content()
+        .unwrap()
+}
+
+#[derive(Properties, PartialEq)]
+struct LoadingProps {
+    show: bool,
+}
+
+#[function_component(Loading)]
+fn loading(props: &LoadingProps) -> Html {
+    html! {
+        <Skeleton show={props.show} width="200px">
+            <p class="content">{ "Loaded" }</p>
+        </Skeleton>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn toggling_show_swaps_skeleton_and_children() {
+    let root = mount_point();
+    let mut app =
+        yew::Renderer::<Loading>::with_root_and_props(root.clone(), LoadingProps { show: false })
+            .render();
+    settle().await;
+    assert!(contains(&root, ".skeleton-rs"));
+    assert!(!contains(&root, ".content"));
+
+    app.update(LoadingProps { show: true });
+    settle().await;
+    assert!(!contains(&root, ".skeleton-rs"));
+    assert!(contains(&root, ".content"));
+
+    app.update(LoadingProps { show: false });
+    settle().await;
+    assert!(contains(&root, ".skeleton-rs"));
+    assert!(!contains(&root, ".content"));
+
+    app.destroy();
+}
+
+#[derive(Properties, PartialEq)]
+struct DelayedProps {
+    show: bool,
+    delay_ms: u32,
+    #[prop_or_default]
+    min_display_ms: u32,
+}
+
+#[function_component(Delayed)]
+fn delayed(props: &DelayedProps) -> Html {
+    html! {
+        <Skeleton
+            show={props.show}
+            delay_ms={props.delay_ms}
+            min_display_ms={props.min_display_ms}
+        >
+            <p class="content">{ "Loaded" }</p>
+        </Skeleton>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn delayed_skeleton_stays_hidden_until_delay_elapses() {
+    let root = mount_point();
+    let app = yew::Renderer::<Delayed>::with_root_and_props(
+        root.clone(),
+        DelayedProps {
+            show: false,
+            delay_ms: 300,
+            min_display_ms: 0,
+        },
+    )
+    .render();
+    settle().await;
+    assert!(!contains(&root, ".skeleton-rs"), "shown before the delay");
+
+    sleep(Duration::from_millis(400)).await;
+    assert!(contains(&root, ".skeleton-rs"));
+    assert!(!contains(&root, ".content"));
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn min_display_only_applies_once_the_delay_elapsed() {
+    let root = mount_point();
+    let props = |show| DelayedProps {
+        show,
+        delay_ms: 300,
+        min_display_ms: 500,
+    };
+    let mut app =
+        yew::Renderer::<Delayed>::with_root_and_props(root.clone(), props(false)).render();
+
+    // Data arriving within the delay skips the skeleton entirely.
+    sleep(Duration::from_millis(100)).await;
+    app.update(props(true));
+    sleep(Duration::from_millis(400)).await;
+    assert!(!contains(&root, ".skeleton-rs"), "shown for a fast load");
+    assert!(contains(&root, ".content"));
+
+    // Data arriving after the delay keeps the skeleton up for `min_display_ms`.
+    app.update(props(false));
+    sleep(Duration::from_millis(400)).await;
+    assert!(contains(&root, ".skeleton-rs"));
+    app.update(props(true));
+    sleep(Duration::from_millis(200)).await;
+    assert!(
+        contains(&root, ".skeleton-rs"),
+        "hidden before min_display_ms"
+    );
+    sleep(Duration::from_millis(500)).await;
+    assert!(!contains(&root, ".skeleton-rs"));
+    assert!(contains(&root, ".content"));
+
+    app.destroy();
+}
+
+#[derive(Properties, PartialEq)]
+struct ThemedProps {
+    theme: Theme,
+}
+
+#[function_component(Themed)]
+fn themed(props: &ThemedProps) -> Html {
+    html! { <Skeleton theme={props.theme.clone()} animation={Animation::Wave} /> }
+}
+
+#[wasm_bindgen_test]
+async fn theme_change_restyles_without_remount() {
+    let root = mount_point();
+    let mut app = yew::Renderer::<Themed>::with_root_and_props(
+        root.clone(),
+        ThemedProps {
+            theme: Theme::Light,
+        },
+    )
+    .render();
+    settle().await;
+    let skeleton = root.query_selector(".skeleton-rs").unwrap().unwrap();
+    let class = skeleton.class_name();
+    assert!(
+        skeleton
+            .get_attribute("style")
+            .unwrap()
+            .contains("--skeleton-rs-base: #e0e0e0;")
+    );
+
+    app.update(ThemedProps { theme: Theme::Dark });
+    settle().await;
+    let restyled = root.query_selector(".skeleton-rs").unwrap().unwrap();
+    assert!(restyled.is_same_node(Some(&skeleton)));
+    assert!(
+        restyled
+            .get_attribute("style")
+            .unwrap()
+            .contains("--skeleton-rs-base: #444444;")
+    );
+    assert_eq!(restyled.class_name(), class);
+
+    app.destroy();
+}
+
+#[function_component(Twins)]
+fn twins() -> Html {
+    html! {
+        <>
+            <Skeleton animation_duration="800ms" />
+            <Skeleton animation_duration="800ms" />
+        </>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn identical_skeletons_share_one_animation_class() {
+    let root = mount_point();
+    let app = yew::Renderer::<Twins>::with_root(root.clone()).render();
+    settle().await;
+    let skeletons = root.query_selector_all(".skeleton-rs").unwrap();
+    assert_eq!(skeletons.length(), 2);
+    let anim_class =
+        |index| animation_class_of(&skeletons.item(index).unwrap().unchecked_into::<Element>());
+    let class = anim_class(0);
+    assert_eq!(anim_class(1), class);
+    let document = web_sys::window().unwrap().document().unwrap();
+    let styles = document
+        .query_selector_all(&format!("style#{class}"))
+        .unwrap();
+    assert_eq!(styles.length(), 1);
+
+    app.destroy();
+}
+
+#[derive(Properties, PartialEq)]
+struct ObservedProps {
+    measure_once: bool,
+}
+
+#[function_component(Observed)]
+fn observed(props: &ObservedProps) -> Html {
+    html! {
+        <Skeleton
+            animate_on_visible=true
+            measure_once={props.measure_once}
+            width="200px"
+            height="20px"
+        />
+    }
+}
+
+#[wasm_bindgen_test]
+async fn measure_once_disconnects_observer_after_measuring() {
+    let before = disconnect_count();
+    let root = mount_point();
+    let app = yew::Renderer::<Observed>::with_root_and_props(
+        root.clone(),
+        ObservedProps { measure_once: true },
+    )
+    .render();
+    // Observers report the initial intersection a frame after observing.
+    sleep(Duration::from_millis(100)).await;
+    assert!(contains(&root, ".skeleton-rs"));
+    assert_eq!(disconnect_count() - before, 1);
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn styles_are_injected_again_after_remove_styles() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let root = mount_point();
+    let app =
+        yew::Renderer::<Loading>::with_root_and_props(root.clone(), LoadingProps { show: false })
+            .render();
+    settle().await;
+    assert!(document.get_element_by_id("skeleton-rs-style").is_some());
+    app.destroy();
+
+    remove_styles();
+    assert!(document.get_element_by_id("skeleton-rs-style").is_none());
+
+    let app =
+        yew::Renderer::<Loading>::with_root_and_props(root, LoadingProps { show: false }).render();
+    settle().await;
+    assert!(document.get_element_by_id("skeleton-rs-style").is_some());
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn unmounting_disconnects_observer() {
+    let before = disconnect_count();
+    let root = mount_point();
+    let app = yew::Renderer::<Observed>::with_root_and_props(
+        root.clone(),
+        ObservedProps {
+            measure_once: false,
+        },
+    )
+    .render();
+    sleep(Duration::from_millis(100)).await;
+    assert!(contains(&root, ".skeleton-rs"));
+    assert_eq!(disconnect_count(), before);
+
+    app.destroy();
+    settle().await;
+    assert_eq!(disconnect_count() - before, 1);
+}
+
+#[derive(Properties, PartialEq)]
+struct WavingProps {
+    direction: Direction,
+}
+
+#[function_component(Waving)]
+fn waving(props: &WavingProps) -> Html {
+    html! { <Skeleton animation={Animation::Wave} direction={props.direction.clone()} /> }
+}
+
+#[wasm_bindgen_test]
+async fn wave_direction_selects_gradient_and_keyframes() {
+    for (direction, angle, keyframes) in [
+        (Direction::RightToLeft, "270deg", "skeleton-rs-wave-rtl"),
+        (Direction::CustomAngle(45), "45deg", "skeleton-rs-wave-a45"),
+    ] {
+        let root = mount_point();
+        let app =
+            yew::Renderer::<Waving>::with_root_and_props(root.clone(), WavingProps { direction })
+                .render();
+        settle().await;
+        let skeleton = root.query_selector(".skeleton-rs").unwrap().unwrap();
+        let rule = stylesheet(&animation_class_of(&skeleton));
+        assert!(rule.contains(&format!("linear-gradient({angle}")));
+        assert!(rule.contains(&format!("animation: {keyframes} ")));
+        assert!(stylesheet(keyframes).contains(&format!("@keyframes {keyframes}")));
+
+        app.destroy();
+    }
+}
+
+#[function_component(Announced)]
+fn announced() -> Html {
+    html! {
+        <>
+            <Skeleton id="decorative" />
+            <Skeleton id="labelled" loading_label="Loading profile" />
+            <Skeleton id="button" tag="button" />
+        </>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn aria_attributes_follow_labels_and_tag() {
+    let root = mount_point();
+    let app = yew::Renderer::<Announced>::with_root(root.clone()).render();
+    settle().await;
+    let attribute = |id: &str, name: &str| {
+        root.query_selector(&format!("#{id}"))
+            .unwrap()
+            .unwrap()
+            .get_attribute(name)
+    };
+
+    assert_eq!(
+        attribute("decorative", "aria-hidden").as_deref(),
+        Some("true")
+    );
+    assert_eq!(
+        attribute("decorative", "role").as_deref(),
+        Some("presentation")
+    );
+
+    assert_eq!(attribute("labelled", "aria-hidden"), None);
+    assert_eq!(attribute("labelled", "role").as_deref(), Some("status"));
+
+    assert_eq!(attribute("button", "aria-hidden"), None);
+    assert_eq!(attribute("button", "role"), None);
+
+    app.destroy();
+}